@@ -0,0 +1,103 @@
+//! Picks words from a generated list of 500,000 words with and without the word index, and
+//! starts games with words from the built-in list the way [`Game::new`] does.
+//!
+//! Run with `cargo bench --bench word_selection`.
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use hangman::{
+    words::{Difficulty, RecentWords, WordFilter, WordList},
+    Game, GameBuilder,
+};
+use rand::{rngs::StdRng, seq::IteratorRandom, Rng, SeedableRng};
+use std::collections::HashSet;
+
+const WORD_COUNT: usize = 500_000;
+const CATEGORY_COUNT: usize = 50;
+
+/// A word pack of random words in two languages and many categories, the same on every run.
+fn generated_words() -> WordList {
+    let mut rng = StdRng::seed_from_u64(45);
+    let mut pack = String::new();
+    let mut generated = HashSet::new();
+
+    for language in ["en", "fr"] {
+        pack.push_str(&format!("@language {}\n", language));
+
+        for category in 0..CATEGORY_COUNT {
+            pack.push_str(&format!("@category category{}\n", category));
+
+            let mut count = 0;
+
+            // Word packs can't have the same word twice.
+            while count < WORD_COUNT / 2 / CATEGORY_COUNT {
+                let length = rng.gen_range(3..=15);
+                let word = (0..length)
+                    .map(|_| rng.gen_range('a'..='z'))
+                    .collect::<String>();
+
+                if generated.insert(word.clone()) {
+                    pack.push_str(&word);
+                    pack.push('\n');
+                    count += 1;
+                }
+            }
+        }
+    }
+
+    WordList::parse(&pack).expect("the generated pack should be valid")
+}
+
+fn word_selection(c: &mut Criterion) {
+    let words = generated_words();
+    let filter = WordFilter::new()
+        .language("fr")
+        .category("category7")
+        .minimum_length(8)
+        .excluding_letters("qxz");
+    let recent = RecentWords::new();
+
+    c.bench_function("index 500k words", |b| {
+        b.iter_batched(
+            generated_words,
+            |words| {
+                let _ = black_box(words.random_entry(&filter));
+                // Returned so that dropping the words isn't timed.
+                words
+            },
+            BatchSize::LargeInput,
+        )
+    });
+    c.bench_function("pick by checking every word", |b| {
+        b.iter(|| {
+            words
+                .entries()
+                .iter()
+                .filter(|entry| filter.matches(entry))
+                .choose(&mut rand::thread_rng())
+        })
+    });
+    c.bench_function("pick from the index", |b| {
+        b.iter(|| words.random_entry(black_box(&filter)))
+    });
+    c.bench_function("pick a fresh word from the index", |b| {
+        b.iter(|| words.random_fresh_entry(black_box(&filter), &recent, 0.5, Difficulty::Medium))
+    });
+    c.bench_function("start a game with Game::new", |b| {
+        b.iter(|| Game::new(black_box(10), black_box(4)))
+    });
+    c.bench_function("start a game with GameBuilder", |b| {
+        b.iter(|| {
+            GameBuilder::new()
+                .minimum_word_size(black_box(4))
+                .maximum_word_size(black_box(8))
+                .build()
+        })
+    });
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = word_selection
+}
+criterion_main!(benches);
@@ -0,0 +1,186 @@
+use crate::i18n::{fill, Locale};
+use hangman::{CompleteGameStatus, Game, GameStatus, GuessStatus};
+
+const GLYPHS: [&str; 2] = ["\u{2713} ", "\u{2717} "];
+
+/// Describes the word as a sentence, e.g. "7 letters, third letter is R, 6 lives left".
+pub(crate) fn describe_word(locale: Locale, game: &Game) -> String {
+    let text = locale.catalogue();
+    let blanked_out_letters = game.blanked_out_letters();
+    let mut revealed_letters: Vec<(String, Vec<usize>)> = Vec::new();
+
+    for (index, letter) in game.letters().into_iter().enumerate() {
+        if blanked_out_letters[index] != letter {
+            continue;
+        }
+
+        match revealed_letters
+            .iter_mut()
+            .find(|(revealed_letter, _)| *revealed_letter == letter)
+        {
+            Some((_, positions)) => positions.push(index),
+            None => revealed_letters.push((letter, vec![index])),
+        }
+    }
+
+    let mut parts = vec![locale.plural(&text.letter_count, blanked_out_letters.len())];
+
+    if revealed_letters.is_empty() {
+        parts.push(String::from(text.no_letters_revealed));
+    }
+
+    for (letter, positions) in revealed_letters {
+        let ordinals = positions
+            .iter()
+            .map(|&position| locale.ordinal(position + 1))
+            .collect::<Vec<String>>();
+
+        parts.push(fill(
+            locale.select(&text.revealed_letter, ordinals.len()),
+            &[
+                ("positions", &join_with_and(&ordinals, text.and)),
+                ("letter", &letter),
+            ],
+        ));
+    }
+
+    parts.push(locale.plural(&text.lives_left, game.lives_remaining()));
+
+    parts.join(", ")
+}
+
+/// Describes the guesses so far, with the letters in alphabetical order rather than the order
+/// they were guessed in.
+pub(crate) fn describe_guesses(locale: Locale, game: &Game) -> String {
+    let text = locale.catalogue();
+    let guesses = game.guesses();
+
+    if guesses.is_empty() {
+        return String::from(text.no_letters_guessed);
+    }
+
+    let letters_with_status = |status: GuessStatus| {
+        let mut letters = guesses
+            .iter()
+            .filter(|(_, guess_status)| *guess_status == status)
+            .map(|(letter, _)| letter.clone())
+            .collect::<Vec<String>>();
+
+        letters.sort();
+
+        match letters.is_empty() {
+            true => String::from(text.none),
+            false => letters.join(", "),
+        }
+    };
+
+    fill(
+        &locale.plural(&text.guessed_letters, guesses.len()),
+        &[
+            ("correct", &letters_with_status(GuessStatus::Correct)),
+            ("incorrect", &letters_with_status(GuessStatus::Incorrect)),
+        ],
+    )
+}
+
+pub(crate) fn describe_complete_game(locale: Locale, game: &Game) -> Option<String> {
+    let text = locale.catalogue();
+
+    let GameStatus::Complete(complete_game_status) = game.status() else {
+        return None;
+    };
+
+    let word = game.letters().concat();
+
+    Some(match complete_game_status {
+        CompleteGameStatus::Won => fill(
+            &locale.plural(&text.won_description, game.lives_remaining()),
+            &[("word", &word)],
+        ),
+        CompleteGameStatus::Lost => fill(text.lost_description, &[("word", &word)]),
+    })
+}
+
+/// Removes the tick or cross at the start of a message, as the words already say the same thing.
+pub(crate) fn without_glyph(message: &str) -> &str {
+    GLYPHS
+        .iter()
+        .find_map(|glyph| message.strip_prefix(glyph))
+        .unwrap_or(message)
+}
+
+fn join_with_and(items: &[String], and: &str) -> String {
+    match items {
+        [] => String::new(),
+        [item] => item.clone(),
+        [rest @ .., last] => format!("{} {} {}", rest.join(", "), and, last),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_word_is_described_with_its_length_revealed_letters_and_lives() {
+        let mut game = Game::from_word("rustacean", 7).unwrap();
+
+        assert_eq!(
+            describe_word(Locale::English, &game),
+            "9 letters, no letters revealed yet, 7 lives left"
+        );
+
+        let _ = game.make_guess("s");
+        let _ = game.make_guess("a");
+        let _ = game.make_guess("z");
+
+        assert_eq!(
+            describe_word(Locale::English, &game),
+            "9 letters, third letter is S, fifth and eighth letters are A, 6 lives left"
+        );
+    }
+
+    #[test]
+    fn guesses_are_described_in_alphabetical_order() {
+        let mut game = Game::from_word("crate", 1).unwrap();
+
+        assert_eq!(
+            describe_guesses(Locale::English, &game),
+            "No letters guessed yet."
+        );
+
+        let _ = game.make_guess("t");
+        let _ = game.make_guess("c");
+
+        assert_eq!(
+            describe_guesses(Locale::English, &game),
+            "You have guessed 2 letters. In the word: C, T. Not in the word: none."
+        );
+    }
+
+    #[test]
+    fn descriptions_are_translated_into_the_chosen_language() {
+        let mut game = Game::from_word("crate", 1).unwrap();
+
+        let _ = game.make_guess("r");
+        let _ = game.make_guess("z");
+
+        assert_eq!(
+            describe_word(Locale::French, &game),
+            "5 lettres, la deuxième lettre est R, 0 vie restante"
+        );
+        assert_eq!(
+            describe_complete_game(Locale::French, &game),
+            Some(String::from(
+                "Vous n'avez plus de vies. Le mot était CRATE."
+            ))
+        );
+    }
+
+    #[test]
+    fn glyphs_are_removed_from_the_start_of_messages() {
+        assert_eq!(without_glyph("\u{2713} Nice job!"), "Nice job!");
+        assert_eq!(without_glyph("\u{2717} Sorry!"), "Sorry!");
+        assert_eq!(without_glyph("Already guessed"), "Already guessed");
+    }
+}
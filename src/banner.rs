@@ -0,0 +1,10 @@
+pub(crate) const BANNER_LINES: [&str; 8] = [
+    "===============================================",
+    " _   _                                         ",
+    "| | | | __ _ _ __   __ _ _ __ ___   __ _ _ __  ",
+    "| |_| |/ _` | '_ \\ / _` | '_ ` _ \\ / _` | '_ \\ ",
+    "|  _  | (_| | | | | (_| | | | | | | (_| | | | |",
+    "|_| |_|\\__,_|_| |_|\\__, |_| |_| |_|\\__,_|_| |_|",
+    "                   |___/                       ",
+    "===============================================",
+];
@@ -0,0 +1,210 @@
+use crate::i18n::Locale;
+use std::path::PathBuf;
+
+const LANGUAGE_ARGUMENT: &str = "--lang";
+const DAILY_ARGUMENT: &str = "--daily";
+const FORGET_RECENT_WORDS_ARGUMENT: &str = "--forget-recent-words";
+const WORDS_COMMAND: &str = "words";
+/// Options that change a setting for one run, with the name of the setting.
+const SETTING_ARGUMENTS: [(&str, &str); 4] = [
+    ("--word-size", "word_size"),
+    ("--min-word-size", "minimum_word_size"),
+    ("--max-word-size", "maximum_word_size"),
+    ("--unique-letters", "unique_letters"),
+];
+
+/// A `words` command for checking word packs instead of playing.
+#[derive(Debug, PartialEq)]
+pub(crate) enum WordsCommand {
+    /// Reports every mistake in the word packs and how long their words are.
+    Lint(Vec<PathBuf>),
+    /// Reports how common each letter is and how hard each word is likely to be.
+    Stats(Vec<PathBuf>),
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct Arguments {
+    pub(crate) locale: Option<Locale>,
+    /// Whether to play the word of the day.
+    pub(crate) daily: bool,
+    /// Whether to forget the recently played words, so that any word can come up again.
+    pub(crate) forget_recent_words: bool,
+    pub(crate) words_command: Option<WordsCommand>,
+    /// Settings to use instead of the ones in the config file, by name, in the order given.
+    pub(crate) settings: Vec<(&'static str, String)>,
+}
+
+impl Arguments {
+    /// Parses the arguments after the program name.
+    pub(crate) fn parse(arguments: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut parsed = Arguments::default();
+        let mut arguments = arguments.into_iter();
+
+        while let Some(argument) = arguments.next() {
+            if argument == DAILY_ARGUMENT {
+                parsed.daily = true;
+                continue;
+            }
+
+            if argument == FORGET_RECENT_WORDS_ARGUMENT {
+                parsed.forget_recent_words = true;
+                continue;
+            }
+
+            if argument == WORDS_COMMAND && parsed.words_command.is_none() {
+                parsed.words_command = Some(match arguments.next().as_deref() {
+                    Some("lint") => WordsCommand::Lint(Vec::new()),
+                    Some("stats") => WordsCommand::Stats(Vec::new()),
+                    _ => return Err(format!("{} needs a command: lint or stats", WORDS_COMMAND)),
+                });
+                continue;
+            }
+
+            // Anything that isn't an option after a words command is a word pack to check.
+            if let Some(WordsCommand::Lint(paths) | WordsCommand::Stats(paths)) =
+                &mut parsed.words_command
+            {
+                if !argument.starts_with('-') {
+                    paths.push(PathBuf::from(argument));
+                    continue;
+                }
+            }
+
+            let (name, value) = match argument.split_once('=') {
+                Some((name, value)) => (name, Some(value.to_string())),
+                None => (argument.as_str(), None),
+            };
+
+            if let Some((_, setting)) = SETTING_ARGUMENTS.iter().find(|(option, _)| *option == name)
+            {
+                let value = match value {
+                    Some(value) => value,
+                    None => arguments
+                        .next()
+                        .ok_or_else(|| format!("{} needs a number", name))?,
+                };
+
+                parsed.settings.push((setting, value));
+                continue;
+            }
+
+            if name != LANGUAGE_ARGUMENT {
+                return Err(format!("unknown argument \"{}\"", argument));
+            }
+
+            let language = match value {
+                Some(language) => language,
+                None => arguments
+                    .next()
+                    .ok_or_else(|| format!("{} needs a language code", LANGUAGE_ARGUMENT))?,
+            };
+
+            parsed.locale = Some(Locale::from_code(&language).ok_or_else(|| {
+                format!(
+                    "unsupported language \"{}\", expected one of: {}",
+                    language,
+                    Locale::ALL.map(Locale::code).join(", ")
+                )
+            })?);
+        }
+
+        Ok(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(arguments: &[&str]) -> Result<Arguments, String> {
+        Arguments::parse(arguments.iter().map(|argument| argument.to_string()))
+    }
+
+    #[test]
+    fn the_language_can_be_given_as_one_or_two_arguments() {
+        assert_eq!(
+            parse(&["--lang", "fr"]),
+            Ok(Arguments {
+                locale: Some(Locale::French),
+                ..Arguments::default()
+            })
+        );
+        assert_eq!(
+            parse(&["--lang=en_GB", "--daily", "--forget-recent-words"]),
+            Ok(Arguments {
+                locale: Some(Locale::English),
+                daily: true,
+                forget_recent_words: true,
+                ..Arguments::default()
+            })
+        );
+        assert_eq!(parse(&[]), Ok(Arguments::default()));
+    }
+
+    #[test]
+    fn word_size_options_override_the_settings_in_order() {
+        assert_eq!(
+            parse(&[
+                "--min-word-size",
+                "3",
+                "--max-word-size=6",
+                "--unique-letters",
+                "any",
+                "--word-size=5",
+            ])
+            .map(|arguments| arguments.settings),
+            Ok(vec![
+                ("minimum_word_size", String::from("3")),
+                ("maximum_word_size", String::from("6")),
+                ("unique_letters", String::from("any")),
+                ("word_size", String::from("5")),
+            ])
+        );
+        assert_eq!(
+            parse(&["--word-size"]),
+            Err(String::from("--word-size needs a number"))
+        );
+    }
+
+    #[test]
+    fn the_words_command_takes_the_word_packs_to_check() {
+        assert_eq!(
+            parse(&["words", "lint", "animals.pack", "--lang", "fr", "food.pack"]),
+            Ok(Arguments {
+                locale: Some(Locale::French),
+                words_command: Some(WordsCommand::Lint(vec![
+                    PathBuf::from("animals.pack"),
+                    PathBuf::from("food.pack")
+                ])),
+                ..Arguments::default()
+            })
+        );
+        assert_eq!(
+            parse(&["words", "stats"]).map(|arguments| arguments.words_command),
+            Ok(Some(WordsCommand::Stats(Vec::new())))
+        );
+        assert_eq!(
+            parse(&["words", "count"]),
+            Err(String::from("words needs a command: lint or stats"))
+        );
+        assert_eq!(
+            parse(&["animals.pack"]),
+            Err(String::from("unknown argument \"animals.pack\""))
+        );
+    }
+
+    #[test]
+    fn unknown_arguments_and_languages_return_an_error() {
+        assert_eq!(
+            parse(&["--colour"]),
+            Err(String::from("unknown argument \"--colour\""))
+        );
+        assert_eq!(
+            parse(&["--lang", "de"]),
+            Err(String::from(
+                "unsupported language \"de\", expected one of: en, fr"
+            ))
+        );
+        assert!(parse(&["--lang"]).is_err());
+    }
+}
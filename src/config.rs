@@ -0,0 +1,159 @@
+use std::{
+    env, fmt, fs,
+    io::{self, ErrorKind},
+    path::PathBuf,
+};
+
+const CONFIG_PATH_VARIABLE: &str = "HANGMAN_CONFIG";
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct ConfigEntry {
+    pub(crate) section: String,
+    pub(crate) key: String,
+    pub(crate) value: String,
+    pub(crate) line: usize,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct Config {
+    entries: Vec<ConfigEntry>,
+}
+
+#[derive(Debug)]
+pub(crate) enum ConfigError {
+    Io(PathBuf, io::Error),
+    Syntax { line: usize, message: String },
+    Value { line: usize, message: String },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io(path, error) => {
+                write!(
+                    f,
+                    "could not read config file {}: {}",
+                    path.display(),
+                    error
+                )
+            }
+            ConfigError::Syntax { line, message } | ConfigError::Value { line, message } => {
+                write!(f, "config file line {}: {}", line, message)
+            }
+        }
+    }
+}
+
+impl Config {
+    pub(crate) fn load() -> Result<Self, ConfigError> {
+        let Some(path) = Self::path() else {
+            return Ok(Config::default());
+        };
+
+        match fs::read_to_string(&path) {
+            Ok(contents) => Self::parse(&contents),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(Config::default()),
+            Err(error) => Err(ConfigError::Io(path, error)),
+        }
+    }
+
+    pub(crate) fn path() -> Option<PathBuf> {
+        if let Some(path) = env::var_os(CONFIG_PATH_VARIABLE) {
+            return Some(PathBuf::from(path));
+        }
+
+        let config_directory = env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+
+        Some(config_directory.join("hangman").join("config.ini"))
+    }
+
+    pub(crate) fn parse(contents: &str) -> Result<Self, ConfigError> {
+        let mut entries = Vec::new();
+        let mut section = String::new();
+
+        for (index, line) in contents.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+                continue;
+            }
+
+            if let Some(name) = line.strip_prefix('[') {
+                let Some(name) = name.strip_suffix(']') else {
+                    return Err(ConfigError::Syntax {
+                        line: line_number,
+                        message: String::from("section header is missing a closing \"]\""),
+                    });
+                };
+
+                section = name.trim().to_lowercase();
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(ConfigError::Syntax {
+                    line: line_number,
+                    message: format!("expected \"key = value\" but found \"{}\"", line),
+                });
+            };
+
+            entries.push(ConfigEntry {
+                section: section.clone(),
+                key: key.trim().to_lowercase(),
+                value: value.trim().to_string(),
+                line: line_number,
+            });
+        }
+
+        Ok(Config { entries })
+    }
+
+    pub(crate) fn entries(&self) -> &[ConfigEntry] {
+        &self.entries
+    }
+
+    pub(crate) fn section<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a ConfigEntry> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.section == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsing_a_config_groups_entries_by_section_and_ignores_comments() {
+        let config = Config::parse("# comment\n[Keys]\nExit = Ctrl-C\n\n[other]\nvalue=1\n")
+            .expect("config should parse");
+
+        assert_eq!(
+            config.section("keys").collect::<Vec<&ConfigEntry>>(),
+            vec![&ConfigEntry {
+                section: String::from("keys"),
+                key: String::from("exit"),
+                value: String::from("Ctrl-C"),
+                line: 3,
+            }]
+        );
+        assert_eq!(config.section("other").count(), 1);
+    }
+
+    #[test]
+    fn parsing_a_line_without_an_equals_sign_returns_a_syntax_error_with_the_line_number() {
+        let result = Config::parse("[keys]\nexit Ctrl-C\n");
+
+        assert!(matches!(result, Err(ConfigError::Syntax { line: 2, .. })));
+    }
+
+    #[test]
+    fn parsing_an_unclosed_section_header_returns_a_syntax_error() {
+        let result = Config::parse("[keys\n");
+
+        assert!(matches!(result, Err(ConfigError::Syntax { line: 1, .. })));
+    }
+}
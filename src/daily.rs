@@ -0,0 +1,250 @@
+//! The word of the day, picked from a [`WordList`] by the date and a salt, so that everyone with
+//! the same words and salt gets the same puzzle without going online.
+//!
+//! ```
+//! use hangman::daily::Date;
+//! use hangman::words::{WordFilter, WordList};
+//!
+//! let words = WordList::built_in();
+//! let filter = WordFilter::new().language("en");
+//! let date: Date = "2024-02-29".parse().expect("2024 is a leap year");
+//!
+//! let entry = words.daily_entry(&filter, date, "team").expect("a word should match");
+//!
+//! assert_eq!(words.daily_entry(&filter, date, "team"), Ok(entry));
+//! ```
+
+use crate::words::{PickWordError, WordEntry, WordFilter, WordList};
+use std::{
+    error::Error,
+    fmt,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// A day in the Gregorian calendar, written as `YYYY-MM-DD`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Date {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+/// A date that isn't a real day written as `YYYY-MM-DD`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseDateError(String);
+
+impl fmt::Display for ParseDateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{}\" is not a date written as YYYY-MM-DD", self.0)
+    }
+}
+
+impl Error for ParseDateError {}
+
+impl Date {
+    /// The date, or `None` if the month or day doesn't exist.
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        let is_leap_year = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+        let days_in_month = match month {
+            2 if is_leap_year => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            1..=12 => 31,
+            _ => return None,
+        };
+
+        (1..=days_in_month)
+            .contains(&day)
+            .then_some(Date { year, month, day })
+    }
+
+    /// Today's date in UTC, so that the day changes at the same moment for everyone.
+    pub fn today() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+
+        Self::from_days_since_epoch((seconds / SECONDS_PER_DAY) as i64)
+    }
+
+    /// The date a number of days after 1 January 1970.
+    pub fn from_days_since_epoch(days: i64) -> Self {
+        // Counts in 400 year eras starting on 1 March, so that leap days fall at the end of a year.
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_from_march = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+        let month = if month_from_march < 10 {
+            month_from_march + 3
+        } else {
+            month_from_march - 9
+        };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+        Date {
+            year: year as i32,
+            month: month as u32,
+            day: day as u32,
+        }
+    }
+
+    /// The year, such as 2024.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// The month, from 1 for January to 12 for December.
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// The day of the month, starting from 1.
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for Date {
+    type Err = ParseDateError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let error = || ParseDateError(value.to_string());
+        let parts = value.trim().split('-').collect::<Vec<&str>>();
+
+        let [year, month, day] = parts.as_slice() else {
+            return Err(error());
+        };
+
+        Date::new(
+            year.parse().map_err(|_| error())?,
+            month.parse().map_err(|_| error())?,
+            day.parse().map_err(|_| error())?,
+        )
+        .ok_or_else(error)
+    }
+}
+
+/// Hashes the date and salt with 64-bit FNV-1a, which gives the same number on every machine.
+pub fn seed(date: Date, salt: &str) -> u64 {
+    format!("{}:{}", date, salt)
+        .bytes()
+        .fold(FNV_OFFSET_BASIS, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+        })
+}
+
+impl WordList {
+    /// Picks the word of the day from the words that match a filter. The same words, filter, date
+    /// and salt always pick the same word.
+    pub fn daily_entry(
+        &self,
+        filter: &WordFilter,
+        date: Date,
+        salt: &str,
+    ) -> Result<&WordEntry, PickWordError> {
+        let matching = self.matching(filter).collect::<Vec<&WordEntry>>();
+
+        if matching.is_empty() {
+            return Err(self.pick_error(filter));
+        }
+
+        Ok(matching[(seed(date, salt) % matching.len() as u64) as usize])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_since_the_epoch_are_converted_to_calendar_dates() {
+        assert_eq!(
+            Date::from_days_since_epoch(0),
+            Date::new(1970, 1, 1).unwrap()
+        );
+        assert_eq!(
+            Date::from_days_since_epoch(-1),
+            Date::new(1969, 12, 31).unwrap()
+        );
+        assert_eq!(
+            Date::from_days_since_epoch(11_017),
+            Date::new(2000, 3, 1).unwrap()
+        );
+        assert_eq!(
+            Date::from_days_since_epoch(19_782),
+            Date::new(2024, 2, 29).unwrap()
+        );
+    }
+
+    #[test]
+    fn dates_are_read_and_written_as_year_month_day() {
+        assert_eq!("2024-02-29".parse(), Ok(Date::new(2024, 2, 29).unwrap()));
+        assert_eq!(Date::new(987, 6, 5).unwrap().to_string(), "0987-06-05");
+        assert_eq!(
+            "2023-02-29".parse::<Date>().unwrap_err().to_string(),
+            "\"2023-02-29\" is not a date written as YYYY-MM-DD"
+        );
+        assert!("2024-13-01".parse::<Date>().is_err());
+        assert!("29/02/2024".parse::<Date>().is_err());
+    }
+
+    #[test]
+    fn the_seed_is_an_fnv_hash_of_the_date_and_salt() {
+        let date = Date::new(2024, 2, 29).unwrap();
+
+        assert_eq!(seed(date, "team"), 17_593_891_330_248_559_315);
+        assert_ne!(seed(date, "team"), seed(date, "other team"));
+    }
+
+    #[test]
+    fn the_daily_word_only_depends_on_the_words_filter_date_and_salt() {
+        let words = WordList::parse(
+            "@language en\n@category animals\ncat\ndog\nhorse\nmouse\nsheep\n\
+             @language fr\n@category animaux\nchat\n",
+        )
+        .unwrap();
+        let filter = WordFilter::new().language("en");
+        let picks = |salt| {
+            (0..30)
+                .map(|days| {
+                    words
+                        .daily_entry(&filter, Date::from_days_since_epoch(days), salt)
+                        .map(|entry| entry.word.clone())
+                        .ok()
+                })
+                .collect::<Vec<Option<String>>>()
+        };
+
+        assert_eq!(picks("team"), picks("team"));
+        assert_ne!(picks("team"), picks("other team"));
+        assert!(picks("team")
+            .iter()
+            .all(|word| word.as_ref().is_some_and(|word| word != "CHAT")));
+        assert_eq!(
+            words.daily_entry(
+                &WordFilter::new().category("food"),
+                Date::from_days_since_epoch(0),
+                "team"
+            ),
+            Err(PickWordError::NoMatchingWords(
+                WordFilter::new().category("food")
+            ))
+        );
+    }
+}
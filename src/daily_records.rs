@@ -0,0 +1,293 @@
+use crate::config::{Config, ConfigError};
+use hangman::{daily::Date, CompleteGameStatus, Game, GameStatus, GuessStatus};
+use std::{
+    fmt, fs,
+    io::{self, ErrorKind},
+    path::PathBuf,
+    str::FromStr,
+};
+
+const DAILY_RECORDS_FILE: &str = "daily.ini";
+const CORRECT_GUESS: char = '+';
+const INCORRECT_GUESS: char = '-';
+
+/// How the word of the day went, kept so that each day's word is only played once.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct DailyRecord {
+    pub(crate) date: Date,
+    pub(crate) status: CompleteGameStatus,
+    pub(crate) lives: usize,
+    pub(crate) lives_remaining: usize,
+    pub(crate) guesses: Vec<GuessStatus>,
+}
+
+impl DailyRecord {
+    /// Records a game that started with `lives`, counting a game that was left before it ended
+    /// as lost.
+    pub(crate) fn from_game(date: Date, game: &Game, lives: usize) -> Self {
+        DailyRecord {
+            date,
+            status: match game.status() {
+                GameStatus::Complete(status) => status,
+                GameStatus::InProgress => CompleteGameStatus::Lost,
+            },
+            lives,
+            lives_remaining: game.lives_remaining(),
+            guesses: game
+                .guesses()
+                .into_iter()
+                .map(|(_, status)| status)
+                .collect(),
+        }
+    }
+}
+
+/// The results of the words of the day played so far, saved to `daily.ini` next to the config
+/// file.
+#[derive(Debug, Default)]
+pub(crate) struct DailyRecords {
+    path: Option<PathBuf>,
+    records: Vec<DailyRecord>,
+}
+
+#[derive(Debug)]
+pub(crate) enum DailyRecordsError {
+    Io(PathBuf, io::Error),
+    Invalid {
+        path: PathBuf,
+        line: usize,
+        message: String,
+    },
+}
+
+impl fmt::Display for DailyRecordsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DailyRecordsError::Io(path, error) => {
+                write!(f, "could not read {}: {}", path.display(), error)
+            }
+            DailyRecordsError::Invalid {
+                path,
+                line,
+                message,
+            } => write!(
+                f,
+                "daily results {} line {}: {}",
+                path.display(),
+                line,
+                message
+            ),
+        }
+    }
+}
+
+impl DailyRecords {
+    pub(crate) fn load() -> Result<Self, DailyRecordsError> {
+        match Config::path().and_then(|path| Some(path.parent()?.join(DAILY_RECORDS_FILE))) {
+            Some(path) => Self::load_from(path),
+            None => Ok(DailyRecords::default()),
+        }
+    }
+
+    fn load_from(path: PathBuf) -> Result<Self, DailyRecordsError> {
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == ErrorKind::NotFound => String::new(),
+            Err(error) => return Err(DailyRecordsError::Io(path, error)),
+        };
+
+        match parse(&contents) {
+            Ok(records) => Ok(DailyRecords {
+                path: Some(path),
+                records,
+            }),
+            Err((line, message)) => Err(DailyRecordsError::Invalid {
+                path,
+                line,
+                message,
+            }),
+        }
+    }
+
+    pub(crate) fn get(&self, date: Date) -> Option<&DailyRecord> {
+        self.records.iter().find(|record| record.date == date)
+    }
+
+    /// Adds the result of a day that hasn't been played yet and saves every result, keeping the
+    /// result in memory even if it can't be saved.
+    pub(crate) fn record(&mut self, record: DailyRecord) -> io::Result<()> {
+        if self.get(record.date).is_some() {
+            return Ok(());
+        }
+
+        self.records.push(record);
+
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory)?;
+        }
+
+        fs::write(path, self.to_ini())
+    }
+
+    fn to_ini(&self) -> String {
+        self.records
+            .iter()
+            .map(|record| {
+                format!(
+                    "[{}]\nresult = {}\nlives = {}\nlives_remaining = {}\nguesses = {}\n",
+                    record.date,
+                    match record.status {
+                        CompleteGameStatus::Won => "won",
+                        CompleteGameStatus::Lost => "lost",
+                    },
+                    record.lives,
+                    record.lives_remaining,
+                    record
+                        .guesses
+                        .iter()
+                        .map(|status| match status {
+                            GuessStatus::Correct => CORRECT_GUESS,
+                            GuessStatus::Incorrect => INCORRECT_GUESS,
+                        })
+                        .collect::<String>()
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+/// Reads the results, returning the line and message of the first mistake.
+fn parse(contents: &str) -> Result<Vec<DailyRecord>, (usize, String)> {
+    let config = Config::parse(contents).map_err(|error| match error {
+        ConfigError::Syntax { line, message } | ConfigError::Value { line, message } => {
+            (line, message)
+        }
+        ConfigError::Io(..) => unreachable!("parsing doesn't read any files"),
+    })?;
+
+    let mut records = Vec::new();
+
+    for entry in config.entries() {
+        let error = |message: String| (entry.line, message);
+        let date =
+            Date::from_str(&entry.section).map_err(|date_error| error(date_error.to_string()))?;
+
+        let record = match records
+            .iter()
+            .position(|record: &DailyRecord| record.date == date)
+        {
+            Some(index) => &mut records[index],
+            None => {
+                records.push(DailyRecord {
+                    date,
+                    status: CompleteGameStatus::Lost,
+                    lives: 0,
+                    lives_remaining: 0,
+                    guesses: Vec::new(),
+                });
+                records.last_mut().expect("a record was just added")
+            }
+        };
+        let number = || {
+            usize::from_str(&entry.value).map_err(|_| {
+                error(format!(
+                    "\"{}\" must be a whole number but was \"{}\"",
+                    entry.key, entry.value
+                ))
+            })
+        };
+
+        match entry.key.as_str() {
+            "result" => {
+                record.status = match entry.value.as_str() {
+                    "won" => CompleteGameStatus::Won,
+                    "lost" => CompleteGameStatus::Lost,
+                    _ => {
+                        return Err(error(format!(
+                            "\"result\" must be won or lost but was \"{}\"",
+                            entry.value
+                        )))
+                    }
+                }
+            }
+            "lives" => record.lives = number()?,
+            "lives_remaining" => record.lives_remaining = number()?,
+            "guesses" => {
+                record.guesses = entry
+                    .value
+                    .chars()
+                    .map(|char| match char {
+                        CORRECT_GUESS => Ok(GuessStatus::Correct),
+                        INCORRECT_GUESS => Ok(GuessStatus::Incorrect),
+                        _ => Err(error(format!(
+                            "\"guesses\" must only contain \"{}\" and \"{}\" but was \"{}\"",
+                            CORRECT_GUESS, INCORRECT_GUESS, entry.value
+                        ))),
+                    })
+                    .collect::<Result<Vec<GuessStatus>, (usize, String)>>()?
+            }
+            _ => return Err(error(format!("unknown key \"{}\"", entry.key))),
+        }
+    }
+
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, process};
+
+    #[test]
+    fn results_are_saved_and_loaded_with_one_per_day() {
+        let directory = env::temp_dir().join(format!("hangman-daily-{}", process::id()));
+        let path = directory.join(DAILY_RECORDS_FILE);
+        let date = Date::new(2024, 2, 29).unwrap();
+        let mut game = Game::from_word("cat", 5).unwrap();
+
+        game.make_guess("z").unwrap();
+        game.make_guess("c").unwrap();
+
+        let mut records = DailyRecords::load_from(path.clone()).unwrap();
+        let left_early = DailyRecord::from_game(date, &game, 5);
+
+        records.record(left_early.clone()).unwrap();
+
+        game.make_guess("a").unwrap();
+        game.make_guess("t").unwrap();
+        records
+            .record(DailyRecord::from_game(date, &game, 5))
+            .unwrap();
+
+        let loaded = DailyRecords::load_from(path.clone()).unwrap();
+
+        fs::write(&path, "[2024-02-30]\nresult = won\n").unwrap();
+        let error = DailyRecords::load_from(path.clone()).unwrap_err();
+
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(
+            left_early,
+            DailyRecord {
+                date,
+                status: CompleteGameStatus::Lost,
+                lives: 5,
+                lives_remaining: 4,
+                guesses: vec![GuessStatus::Incorrect, GuessStatus::Correct],
+            }
+        );
+        assert_eq!(loaded.records, [left_early]);
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "daily results {} line 2: \"2024-02-30\" is not a date written as YYYY-MM-DD",
+                path.display()
+            )
+        );
+    }
+}
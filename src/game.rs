@@ -1,62 +1,208 @@
-use crate::game::MakeGuessSuccess::{Correct, Incorrect};
-use words::random_word;
-
-pub(crate) mod allowed_letters;
-mod words;
-
-pub(crate) struct Game {
+//! The [`Game`] and the results of playing it.
+
+use crate::{
+    game::MakeGuessSuccess::{Correct, Incorrect},
+    words::{PickWordError, WordEntry, WordFilter, WordList},
+};
+use events::Observers;
+use history::History;
+use rand::{seq::SliceRandom, thread_rng};
+use std::{error::Error, fmt};
+
+mod allowed_letters;
+mod builder;
+mod events;
+mod history;
+
+pub use allowed_letters::ALLOWED_LETTER_RANGE;
+pub use builder::{BuildGameError, GameBuilder, Rules};
+pub use events::{GameEvent, GameObserver};
+pub use history::{Move, UndoError, UndoResult};
+
+/// The characters besides letters that a phrase may contain. They are shown from the start.
+pub const PHRASE_SEPARATORS: [char; 3] = [' ', '-', '\''];
+
+/// A single game of Hangman: a word to guess, the guesses made so far and the lives remaining.
+///
+/// Use [`GameBuilder`] to set up a game with a phrase, pre-revealed letters or different
+/// [`Rules`].
+#[derive(Clone, Debug)]
+pub struct Game {
     status: GameStatus,
     word: String,
     guesses: Vec<(String, GuessStatus)>,
+    revealed_letters: Vec<String>,
+    lives: usize,
     lives_remaining: usize,
+    rules: Rules,
+    entry: Option<WordEntry>,
+    history: History,
+    observers: Observers,
 }
 
+/// Whether a game is still being played.
 #[derive(Clone, Debug, PartialEq)]
-pub(crate) enum GameStatus {
+pub enum GameStatus {
+    /// The word hasn't been guessed and there are lives remaining.
     InProgress,
+    /// The game is over and no more guesses can be made.
     Complete(CompleteGameStatus),
 }
 
+/// How a game ended.
 #[derive(Clone, Debug, PartialEq)]
-pub(crate) enum CompleteGameStatus {
+pub enum CompleteGameStatus {
+    /// Every letter in the word was guessed.
     Won,
+    /// The lives ran out before the word was guessed.
     Lost,
 }
 
+/// Whether a guessed letter is in the word.
 #[derive(Clone, Debug, PartialEq)]
-pub(crate) enum GuessStatus {
+pub enum GuessStatus {
+    /// The letter is in the word.
     Correct,
+    /// The letter is not in the word, so it cost a life.
     Incorrect,
 }
 
+/// A guess that was accepted, holding the guessed letter in uppercase.
 #[derive(Clone, Debug, PartialEq)]
-pub(crate) enum MakeGuessSuccess {
+pub enum MakeGuessSuccess {
+    /// The letter is in the word.
     Correct(String),
+    /// The letter is not in the word, so it cost a life.
     Incorrect(String),
 }
 
+/// A guess that was rejected. Rejected guesses never cost a life.
 #[derive(Clone, Debug, PartialEq)]
-pub(crate) enum MakeGuessError {
+#[non_exhaustive]
+pub enum MakeGuessError {
+    /// Nothing was guessed.
     Empty,
+    /// More than one letter was guessed.
     TooLong,
+    /// The guess wasn't a letter from A to Z.
     Invalid,
+    /// The letter, in uppercase, has already been guessed.
     AlreadyGuessed(String),
+    /// The game is already over.
+    GameComplete,
+}
+
+impl fmt::Display for MakeGuessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MakeGuessError::Empty => write!(f, "the guess was empty"),
+            MakeGuessError::TooLong => write!(f, "the guess was more than one letter"),
+            MakeGuessError::Invalid => write!(f, "the guess wasn't a letter from A to Z"),
+            MakeGuessError::AlreadyGuessed(letter) => {
+                write!(f, "\"{}\" has already been guessed", letter)
+            }
+            MakeGuessError::GameComplete => write!(f, "the game is already over"),
+        }
+    }
+}
+
+impl Error for MakeGuessError {}
+
+/// The result of [`Game::make_guess`].
+pub type MakeGuessResult = Result<MakeGuessSuccess, MakeGuessError>;
+
+/// A hint that couldn't be given.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum HintError {
+    /// A hint costs a life, so it can't be used on the last one.
+    NotEnoughLives,
+    /// The game is already over.
     GameComplete,
 }
 
-pub(crate) type MakeGuessResult = Result<MakeGuessSuccess, MakeGuessError>;
+impl fmt::Display for HintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HintError::NotEnoughLives => write!(f, "a hint needs more than one life remaining"),
+            HintError::GameComplete => write!(f, "the game is already over"),
+        }
+    }
+}
+
+impl Error for HintError {}
+
+/// The result of [`Game::use_hint`], holding the revealed letter.
+pub type HintResult = Result<String, HintError>;
+
+/// The result of [`Game::solve`], holding whether the attempt was correct.
+pub type SolveResult = Result<bool, MakeGuessError>;
+
+/// A word that a game can't be played with.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum InvalidWordError {
+    /// The word was empty.
+    Empty,
+    /// The word contained a character other than the letters A to Z.
+    InvalidCharacter(char),
+}
+
+impl fmt::Display for InvalidWordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvalidWordError::Empty => write!(f, "the word was empty"),
+            InvalidWordError::InvalidCharacter(char) => write!(
+                f,
+                "the word contained \"{}\" but may only contain the letters A to Z",
+                char
+            ),
+        }
+    }
+}
+
+impl Error for InvalidWordError {}
 
 impl Game {
-    pub(crate) fn new(lives: usize, minimum_word_size: usize) -> Self {
-        Game {
+    /// Starts a game with a random word of at least `minimum_word_size` letters, or returns an
+    /// error if no built-in word is that long.
+    pub fn new(lives: usize, minimum_word_size: usize) -> Result<Self, PickWordError> {
+        let entry = random_entry(&WordFilter::new().minimum_length(minimum_word_size))?;
+
+        Ok(Game {
             status: GameStatus::InProgress,
-            word: random_word(minimum_word_size).to_uppercase(),
+            word: entry.word.clone(),
             guesses: Vec::new(),
+            revealed_letters: Vec::new(),
+            lives,
             lives_remaining: lives,
-        }
+            rules: Rules::default(),
+            entry: Some(entry),
+            history: History::default(),
+            observers: Observers::default(),
+        })
+    }
+
+    /// Starts a game with a chosen word, which is case-insensitive but may only contain the
+    /// letters A to Z.
+    pub fn from_word(word: &str, lives: usize) -> Result<Self, InvalidWordError> {
+        Ok(Game {
+            status: GameStatus::InProgress,
+            word: parse_word(word, false)?,
+            guesses: Vec::new(),
+            revealed_letters: Vec::new(),
+            lives,
+            lives_remaining: lives,
+            rules: Rules::default(),
+            entry: None,
+            history: History::default(),
+            observers: Observers::default(),
+        })
     }
 
-    pub(crate) fn make_guess(&mut self, guess: &str) -> MakeGuessResult {
+    /// Guesses a single letter, which is case-insensitive. A correct guess reveals the letter
+    /// wherever it appears in the word, and an incorrect guess costs a life.
+    pub fn make_guess(&mut self, guess: &str) -> MakeGuessResult {
         let guess = &guess.to_uppercase();
 
         if self.status != GameStatus::InProgress {
@@ -70,19 +216,29 @@ impl Game {
         }
 
         let is_correct = self.is_letter_in_word(guess);
+        let status = if is_correct {
+            GuessStatus::Correct
+        } else {
+            GuessStatus::Incorrect
+        };
+
+        self.guesses.push((guess.clone(), status.clone()));
+        self.observers.notify(GameEvent::GuessMade {
+            letter: guess.clone(),
+            status: status.clone(),
+        });
+
+        let lives_lost = if is_correct {
+            self.notify_revealed(guess);
+            0
+        } else {
+            self.lose_lives(1)
+        };
 
-        self.guesses.push((
-            guess.clone(),
-            if is_correct {
-                GuessStatus::Correct
-            } else {
-                GuessStatus::Incorrect
-            },
-        ));
-
-        if !is_correct {
-            self.lives_remaining -= 1;
-        }
+        self.history.record(Move {
+            guesses: vec![(guess.clone(), status)],
+            lives_lost,
+        });
 
         self.update_status();
 
@@ -93,26 +249,74 @@ impl Game {
         }
     }
 
-    pub(crate) fn status(&self) -> GameStatus {
+    /// Sends every [`GameEvent`] from now on to an observer, after any observers subscribed
+    /// before it. A clone of the game starts without observers.
+    ///
+    /// ```
+    /// use hangman::{Game, GameEvent};
+    /// use std::sync::mpsc;
+    ///
+    /// let mut game = Game::from_word("crate", 3).expect("the word should be valid");
+    /// let (sender, receiver) = mpsc::channel();
+    ///
+    /// game.subscribe(sender);
+    /// game.make_guess("z").expect("z should be a new guess");
+    ///
+    /// assert!(receiver
+    ///     .try_iter()
+    ///     .any(|event| event == GameEvent::LifeLost { lives_lost: 1, lives_remaining: 2 }));
+    /// ```
+    pub fn subscribe(&mut self, observer: impl GameObserver + 'static) {
+        self.observers.push(Box::new(observer));
+    }
+
+    /// Whether the game is in progress, won or lost.
+    pub fn status(&self) -> GameStatus {
         self.status.clone()
     }
 
-    pub(crate) fn lives_remaining(&self) -> usize {
+    /// The number of lives the game started with.
+    pub fn lives(&self) -> usize {
+        self.lives
+    }
+
+    /// The number of lives remaining.
+    pub fn lives_remaining(&self) -> usize {
         self.lives_remaining
     }
 
-    pub(crate) fn guesses(&self) -> Vec<(String, GuessStatus)> {
+    /// The letters guessed so far, in the order they were guessed.
+    pub fn guesses(&self) -> Vec<(String, GuessStatus)> {
         self.guesses.clone()
     }
 
-    pub(crate) fn guess_letters(&self) -> Vec<String> {
+    /// The letters guessed so far, without whether they were correct.
+    pub fn guess_letters(&self) -> Vec<String> {
         self.guesses
             .iter()
             .map(|(guess, _)| guess.clone())
             .collect::<Vec<String>>()
     }
 
-    pub(crate) fn letters(&self) -> Vec<String> {
+    /// The letters revealed from the start by [`GameBuilder::reveal`].
+    pub fn revealed_letters(&self) -> Vec<String> {
+        self.revealed_letters.clone()
+    }
+
+    /// The rules the game is scored by.
+    pub fn rules(&self) -> Rules {
+        self.rules
+    }
+
+    /// The word pack entry the word was picked from, with its category and clue. Games with a
+    /// chosen word or phrase have none.
+    pub fn entry(&self) -> Option<&WordEntry> {
+        self.entry.as_ref()
+    }
+
+    /// Every character of the word or phrase, including the letters that haven't been guessed
+    /// yet.
+    pub fn letters(&self) -> Vec<String> {
         self.word
             .split("")
             .filter(|x| !x.is_empty())
@@ -120,11 +324,12 @@ impl Game {
             .collect::<Vec<String>>()
     }
 
-    pub(crate) fn blanked_out_letters(&self) -> Vec<String> {
+    /// The letters of the word, with an underscore in place of each one that hasn't been guessed.
+    pub fn blanked_out_letters(&self) -> Vec<String> {
         self.letters()
             .iter()
             .map(|x| {
-                if self.guess_letters().contains(x) {
+                if self.is_known(x) {
                     x.clone()
                 } else {
                     String::from('_')
@@ -134,16 +339,59 @@ impl Game {
     }
 
     fn update_status(&mut self) {
+        let was_in_progress = self.status == GameStatus::InProgress;
+
         self.status = if self.unknown_letters_count() == 0 {
             GameStatus::Complete(CompleteGameStatus::Won)
         } else if self.lives_remaining == 0 {
             GameStatus::Complete(CompleteGameStatus::Lost)
         } else {
             GameStatus::InProgress
+        };
+
+        if was_in_progress {
+            match self.status {
+                GameStatus::Complete(CompleteGameStatus::Won) => {
+                    self.observers.notify(GameEvent::GameWon)
+                }
+                GameStatus::Complete(CompleteGameStatus::Lost) => {
+                    self.observers.notify(GameEvent::GameLost)
+                }
+                GameStatus::InProgress => {}
+            }
+        }
+    }
+
+    fn lose_lives(&mut self, lives: usize) -> usize {
+        let lives_lost = lives.min(self.lives_remaining);
+
+        if lives_lost > 0 {
+            self.lives_remaining -= lives_lost;
+            self.observers.notify(GameEvent::LifeLost {
+                lives_lost,
+                lives_remaining: self.lives_remaining,
+            });
         }
+
+        lives_lost
+    }
+
+    fn notify_revealed(&mut self, letter: &String) {
+        let positions = self
+            .letters()
+            .iter()
+            .enumerate()
+            .filter(|(_, x)| *x == letter)
+            .map(|(position, _)| position)
+            .collect();
+
+        self.observers.notify(GameEvent::LetterRevealed {
+            letter: letter.clone(),
+            positions,
+        });
     }
 
-    fn validate_guess(&self, guess: &String) -> Result<(), MakeGuessError> {
+    fn validate_guess(&self, guess: &str) -> Result<(), MakeGuessError> {
         if guess.is_empty() {
             return Err(MakeGuessError::Empty);
         } else if guess.len() > 1 {
@@ -162,18 +410,154 @@ impl Game {
     fn unknown_letters_count(&self) -> usize {
         self.letters()
             .iter()
-            .filter(|letter| !self.guess_letters().contains(letter))
+            .filter(|letter| !self.is_known(letter))
             .count()
     }
 
     fn is_already_guessed(&self, letter: &String) -> bool {
-        self.guess_letters().contains(letter)
+        self.guess_letters().contains(letter) || self.revealed_letters.contains(letter)
     }
+
+    fn is_known(&self, letter: &String) -> bool {
+        self.is_already_guessed(letter)
+            || !letter
+                .chars()
+                .all(|char| ALLOWED_LETTER_RANGE.contains(&char))
+    }
+
     fn is_letter_in_word(&self, letter: &String) -> bool {
         let letters = self.letters();
 
         letters.contains(letter)
     }
+
+    /// Reveals a random letter that hasn't been guessed yet, at the cost of [`Rules::hint_cost`]
+    /// lives.
+    pub fn use_hint(&mut self) -> HintResult {
+        if self.status != GameStatus::InProgress {
+            return Err(HintError::GameComplete);
+        }
+
+        if self.lives_remaining <= self.rules.hint_cost {
+            return Err(HintError::NotEnoughLives);
+        }
+
+        let unknown_letters = self.unknown_letters();
+        let letter = unknown_letters
+            .choose(&mut thread_rng())
+            .expect("a game in progress should always have unknown letters")
+            .clone();
+
+        self.guesses.push((letter.clone(), GuessStatus::Correct));
+        self.observers.notify(GameEvent::HintUsed {
+            letter: letter.clone(),
+        });
+        self.notify_revealed(&letter);
+        let lives_lost = self.lose_lives(self.rules.hint_cost);
+
+        self.history.record(Move {
+            guesses: vec![(letter.clone(), GuessStatus::Correct)],
+            lives_lost,
+        });
+
+        self.update_status();
+
+        Ok(letter)
+    }
+
+    /// Guesses the whole word or phrase at once. A correct attempt reveals every letter, and an
+    /// incorrect attempt costs [`Rules::incorrect_solve_cost`] lives. Only the letters of a phrase
+    /// are compared.
+    pub fn solve(&mut self, attempt: &str) -> SolveResult {
+        let attempt = attempt.trim().to_uppercase();
+
+        if self.status != GameStatus::InProgress {
+            return Err(MakeGuessError::GameComplete);
+        }
+
+        if attempt.is_empty() {
+            return Err(MakeGuessError::Empty);
+        }
+
+        if !attempt
+            .chars()
+            .all(|char| char.is_ascii_alphabetic() || PHRASE_SEPARATORS.contains(&char))
+        {
+            return Err(MakeGuessError::Invalid);
+        }
+
+        let is_correct = only_letters(&attempt) == only_letters(&self.word);
+
+        let mut guesses = Vec::new();
+        let mut lives_lost = 0;
+
+        if is_correct {
+            for letter in self.unknown_letters() {
+                self.notify_revealed(&letter);
+                guesses.push((letter, GuessStatus::Correct));
+            }
+
+            self.guesses.extend(guesses.iter().cloned());
+        } else {
+            lives_lost = self.lose_lives(self.rules.incorrect_solve_cost);
+        }
+
+        self.history.record(Move {
+            guesses,
+            lives_lost,
+        });
+
+        self.update_status();
+
+        Ok(is_correct)
+    }
+
+    fn unknown_letters(&self) -> Vec<String> {
+        let mut unknown_letters = self
+            .letters()
+            .into_iter()
+            .filter(|letter| !self.is_known(letter))
+            .collect::<Vec<String>>();
+
+        unknown_letters.sort();
+        unknown_letters.dedup();
+
+        unknown_letters
+    }
+}
+
+pub(crate) fn parse_word(word: &str, is_phrase: bool) -> Result<String, InvalidWordError> {
+    let word = if is_phrase {
+        word.split_whitespace().collect::<Vec<&str>>().join(" ")
+    } else {
+        word.trim().to_string()
+    }
+    .to_uppercase();
+
+    if only_letters(&word).is_empty() {
+        return Err(InvalidWordError::Empty);
+    }
+
+    let is_allowed = |char: &char| {
+        ALLOWED_LETTER_RANGE.contains(char) || is_phrase && PHRASE_SEPARATORS.contains(char)
+    };
+
+    if let Some(char) = word.chars().find(|char| !is_allowed(char)) {
+        return Err(InvalidWordError::InvalidCharacter(char));
+    }
+
+    Ok(word)
+}
+
+/// Picks a random word that matches a filter from the built-in word packs.
+pub(crate) fn random_entry(filter: &WordFilter) -> Result<WordEntry, PickWordError> {
+    WordList::built_in().random_entry(filter).cloned()
+}
+
+fn only_letters(word: &str) -> String {
+    word.chars()
+        .filter(|char| !PHRASE_SEPARATORS.contains(char))
+        .collect()
 }
 
 #[cfg(test)]
@@ -183,9 +567,13 @@ mod tests {
 
     #[test]
     fn when_starting_the_game_the_status_is_in_progress() {
-        let game = Game::new(1, 1);
+        let game = Game::new(1, 1).unwrap();
 
         assert_eq!(game.status, GameStatus::InProgress);
+        assert_eq!(
+            Game::new(6, 40).unwrap_err().to_string(),
+            "there are no words with at least 40 letters"
+        );
     }
 
     #[test]
@@ -297,7 +685,7 @@ mod tests {
 
         let initial_lives_remaining = game.lives_remaining();
 
-        let result = game.make_guess(&String::from(""));
+        let result = game.make_guess("");
 
         assert_eq!(result, Err(Empty));
         assert_eq!(game.guesses(), vec![]);
@@ -348,21 +736,103 @@ mod tests {
         assert_eq!(game.lives_remaining(), 0);
     }
 
+    #[test]
+    fn when_using_a_hint_then_an_unknown_letter_is_revealed_and_a_life_is_lost() {
+        let mut game = create_game(String::from("test"));
+
+        let _ = game.make_guess("t");
+        let result = game.use_hint();
+
+        assert!(matches!(result.as_deref(), Ok("E") | Ok("S")));
+        assert_eq!(game.guesses().len(), 2);
+        assert_eq!(game.guesses()[1].1, GuessStatus::Correct);
+        assert_eq!(game.lives_remaining(), 9);
+    }
+
+    #[test]
+    fn when_using_a_hint_with_one_life_left_then_an_error_result_is_returned_and_nothing_changes() {
+        let mut game = create_single_life_game(String::from("test"));
+
+        let result = game.use_hint();
+
+        assert_eq!(result, Err(HintError::NotEnoughLives));
+        assert_eq!(game.guesses(), vec![]);
+        assert_eq!(game.lives_remaining(), 1);
+    }
+
+    #[test]
+    fn when_using_a_hint_that_reveals_the_final_letter_then_the_game_is_completed_as_a_win() {
+        let mut game = create_game(String::from("aab"));
+
+        let _ = game.make_guess("a");
+        let result = game.use_hint();
+
+        assert_eq!(result, Ok(String::from("B")));
+        assert_eq!(game.status(), GameStatus::Complete(CompleteGameStatus::Won));
+    }
+
+    #[test]
+    fn when_solving_with_the_correct_word_then_every_letter_is_revealed_and_the_game_is_won() {
+        let mut game = create_game(String::from("test"));
+
+        let result = game.solve("Test");
+
+        assert_eq!(result, Ok(true));
+        assert_eq!(game.blanked_out_letters(), game.letters());
+        assert_eq!(game.lives_remaining(), 10);
+        assert_eq!(game.status(), GameStatus::Complete(CompleteGameStatus::Won));
+    }
+
+    #[test]
+    fn when_solving_with_the_wrong_word_then_a_life_is_lost() {
+        let mut game = create_single_life_game(String::from("test"));
+
+        let result = game.solve("text");
+
+        assert_eq!(result, Ok(false));
+        assert_eq!(game.lives_remaining(), 0);
+        assert_eq!(
+            game.status(),
+            GameStatus::Complete(CompleteGameStatus::Lost)
+        );
+    }
+
+    #[test]
+    fn when_solving_with_an_invalid_word_then_an_error_result_is_returned_and_a_life_is_not_lost() {
+        let mut game = create_game(String::from("test"));
+
+        assert_eq!(game.solve("te5t"), Err(Invalid));
+        assert_eq!(game.solve(" "), Err(Empty));
+        assert_eq!(game.lives_remaining(), 10);
+    }
+
+    #[test]
+    fn when_creating_a_game_from_a_word_then_the_word_is_uppercased_and_trimmed() {
+        let game = Game::from_word(" Crate\n", 3).unwrap();
+
+        assert_eq!(game.letters().concat(), "CRATE");
+        assert_eq!(game.lives_remaining(), 3);
+        assert_eq!(game.status(), GameStatus::InProgress);
+    }
+
+    #[test]
+    fn when_creating_a_game_from_an_invalid_word_then_an_error_is_returned() {
+        assert_eq!(Game::from_word("", 3).unwrap_err(), InvalidWordError::Empty);
+        assert_eq!(
+            Game::from_word("two words", 3).unwrap_err(),
+            InvalidWordError::InvalidCharacter(' ')
+        );
+        assert_eq!(
+            Game::from_word("café", 3).unwrap_err(),
+            InvalidWordError::InvalidCharacter('É')
+        );
+    }
+
     fn create_game(word: String) -> Game {
-        Game {
-            status: GameStatus::InProgress,
-            word: word.to_uppercase(),
-            guesses: Vec::new(),
-            lives_remaining: 10,
-        }
+        GameBuilder::new().word(&word).lives(10).build().unwrap()
     }
 
     fn create_single_life_game(word: String) -> Game {
-        Game {
-            status: GameStatus::InProgress,
-            word: word.to_uppercase(),
-            guesses: Vec::new(),
-            lives_remaining: 1,
-        }
+        GameBuilder::new().word(&word).lives(1).build().unwrap()
     }
 }
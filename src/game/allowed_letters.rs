@@ -1,3 +1,4 @@
 use std::ops::RangeInclusive;
 
-pub(crate) const ALLOWED_LETTER_RANGE: RangeInclusive<char> = 'A'..='Z';
+/// The letters that can be guessed and that words are made of.
+pub const ALLOWED_LETTER_RANGE: RangeInclusive<char> = 'A'..='Z';
@@ -0,0 +1,459 @@
+use crate::{
+    game::{
+        allowed_letters::ALLOWED_LETTER_RANGE, events::Observers, history::History, parse_word,
+        random_entry, Game, GameStatus, InvalidWordError,
+    },
+    words::{PickWordError, WordEntry, WordFilter},
+};
+use std::{error::Error, fmt};
+
+/// Rule options that change how a [`Game`] is scored, set up with [`Rules::new`] and the method
+/// named after each option.
+///
+/// ```
+/// use hangman::Rules;
+///
+/// let rules = Rules::new().hint_cost(2).undo_allowance(0);
+///
+/// assert_eq!(rules.hint_cost, 2);
+/// assert_eq!(rules.undo_cost, Rules::default().undo_cost);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct Rules {
+    /// The lives a hint costs. A hint can never use up the last life.
+    pub hint_cost: usize,
+    /// The lives an incorrect attempt to solve the whole word costs.
+    pub incorrect_solve_cost: usize,
+    /// The number of moves that can be taken back with [`Game::undo`] in each game.
+    pub undo_allowance: usize,
+    /// The lives an undo costs, on top of giving back the lives of the move it took back. An undo
+    /// can never use up the last life.
+    pub undo_cost: usize,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules {
+            hint_cost: 1,
+            incorrect_solve_cost: 1,
+            undo_allowance: 3,
+            undo_cost: 0,
+        }
+    }
+}
+
+impl Rules {
+    /// Starts from the default rules: hints and incorrect attempts to solve the word cost 1 life,
+    /// and 3 free undos can be used in each game.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the lives a hint costs.
+    pub fn hint_cost(mut self, hint_cost: usize) -> Self {
+        self.hint_cost = hint_cost;
+        self
+    }
+
+    /// Sets the lives an incorrect attempt to solve the whole word costs.
+    pub fn incorrect_solve_cost(mut self, incorrect_solve_cost: usize) -> Self {
+        self.incorrect_solve_cost = incorrect_solve_cost;
+        self
+    }
+
+    /// Sets the number of moves that can be taken back in each game.
+    pub fn undo_allowance(mut self, undo_allowance: usize) -> Self {
+        self.undo_allowance = undo_allowance;
+        self
+    }
+
+    /// Sets the lives an undo costs on top of giving back the lives of the move it took back.
+    pub fn undo_cost(mut self, undo_cost: usize) -> Self {
+        self.undo_cost = undo_cost;
+        self
+    }
+}
+
+/// Sets up a [`Game`] with a chosen word or phrase, starting lives, pre-revealed letters and
+/// [`Rules`].
+///
+/// ```
+/// use hangman::{GameBuilder, Rules};
+///
+/// let game = GameBuilder::new()
+///     .phrase("hello world")
+///     .lives(6)
+///     .reveal("lo")
+///     .rules(Rules::new().hint_cost(2))
+///     .build()
+///     .expect("the game should be valid");
+///
+/// assert_eq!(game.blanked_out_letters().concat(), "__LLO _O_L_");
+/// ```
+#[derive(Clone, Debug)]
+pub struct GameBuilder {
+    word: Option<String>,
+    is_phrase: bool,
+    entry: Option<WordEntry>,
+    lives: usize,
+    minimum_word_size: usize,
+    maximum_word_size: Option<usize>,
+    revealed_letters: String,
+    rules: Rules,
+}
+
+/// A reason a [`GameBuilder`] couldn't set up a game.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum BuildGameError {
+    /// The chosen word or phrase can't be played with.
+    InvalidWord(InvalidWordError),
+    /// The game was set up with no lives.
+    NoLives,
+    /// A pre-revealed letter wasn't a letter from A to Z.
+    InvalidRevealedLetter(char),
+    /// A pre-revealed letter, in uppercase, isn't in the word.
+    RevealedLetterNotInWord(char),
+    /// Every letter of the word was pre-revealed, leaving nothing to guess.
+    NothingToGuess,
+    /// No built-in word is between the minimum and maximum word sizes.
+    NoMatchingWord(PickWordError),
+}
+
+impl fmt::Display for BuildGameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildGameError::InvalidWord(error) => write!(f, "{}", error),
+            BuildGameError::NoLives => write!(f, "a game needs at least one life"),
+            BuildGameError::InvalidRevealedLetter(char) => write!(
+                f,
+                "\"{}\" can't be revealed because it isn't a letter from A to Z",
+                char
+            ),
+            BuildGameError::RevealedLetterNotInWord(char) => {
+                write!(
+                    f,
+                    "\"{}\" can't be revealed because it isn't in the word",
+                    char
+                )
+            }
+            BuildGameError::NothingToGuess => {
+                write!(f, "every letter was revealed, leaving nothing to guess")
+            }
+            BuildGameError::NoMatchingWord(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for BuildGameError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            BuildGameError::InvalidWord(error) => Some(error),
+            BuildGameError::NoMatchingWord(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<InvalidWordError> for BuildGameError {
+    fn from(error: InvalidWordError) -> Self {
+        BuildGameError::InvalidWord(error)
+    }
+}
+
+impl Default for GameBuilder {
+    fn default() -> Self {
+        GameBuilder {
+            word: None,
+            is_phrase: false,
+            entry: None,
+            lives: 10,
+            minimum_word_size: 4,
+            maximum_word_size: None,
+            revealed_letters: String::new(),
+            rules: Rules::default(),
+        }
+    }
+}
+
+impl GameBuilder {
+    /// Starts setting up a game with 10 lives and a random word of at least 4 letters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Plays with a chosen word, which is case-insensitive but may only contain the letters A to
+    /// Z.
+    pub fn word(mut self, word: &str) -> Self {
+        self.word = Some(word.to_string());
+        self.is_phrase = false;
+        self.entry = None;
+        self
+    }
+
+    /// Plays with a word picked from a word pack, keeping its category and clue for
+    /// [`Game::entry`].
+    pub fn entry(mut self, entry: WordEntry) -> Self {
+        self.word = Some(entry.word.clone());
+        self.is_phrase = false;
+        self.entry = Some(entry);
+        self
+    }
+
+    /// Plays with a chosen phrase. Spaces, hyphens and apostrophes are shown from the start, and
+    /// runs of whitespace are collapsed into a single space.
+    pub fn phrase(mut self, phrase: &str) -> Self {
+        self.word = Some(phrase.to_string());
+        self.is_phrase = true;
+        self.entry = None;
+        self
+    }
+
+    /// Sets the lives the game starts with.
+    pub fn lives(mut self, lives: usize) -> Self {
+        self.lives = lives;
+        self
+    }
+
+    /// Sets the shortest random word that can be picked when no word or phrase is chosen.
+    pub fn minimum_word_size(mut self, minimum_word_size: usize) -> Self {
+        self.minimum_word_size = minimum_word_size;
+        self
+    }
+
+    /// Sets the longest random word that can be picked when no word or phrase is chosen.
+    pub fn maximum_word_size(mut self, maximum_word_size: usize) -> Self {
+        self.maximum_word_size = Some(maximum_word_size);
+        self
+    }
+
+    /// Shows these letters from the start, without counting them as guesses. Can be called more
+    /// than once.
+    pub fn reveal(mut self, letters: &str) -> Self {
+        self.revealed_letters.push_str(letters);
+        self
+    }
+
+    /// Sets the [`Rules`] the game is scored by.
+    pub fn rules(mut self, rules: Rules) -> Self {
+        self.rules = rules;
+        self
+    }
+
+    /// Validates the options and starts the game.
+    pub fn build(self) -> Result<Game, BuildGameError> {
+        let (word, entry) = match self.word {
+            Some(word) => (parse_word(&word, self.is_phrase)?, self.entry),
+            None => {
+                let filter = WordFilter::new().minimum_length(self.minimum_word_size);
+                let filter = match self.maximum_word_size {
+                    Some(maximum_word_size) => filter.maximum_length(maximum_word_size),
+                    None => filter,
+                };
+                let entry = random_entry(&filter).map_err(BuildGameError::NoMatchingWord)?;
+
+                (entry.word.clone(), Some(entry))
+            }
+        };
+
+        if self.lives == 0 {
+            return Err(BuildGameError::NoLives);
+        }
+
+        let mut revealed_letters = Vec::new();
+
+        for char in self
+            .revealed_letters
+            .chars()
+            .filter(|char| !char.is_whitespace() && *char != ',')
+        {
+            let char = char.to_ascii_uppercase();
+
+            if !ALLOWED_LETTER_RANGE.contains(&char) {
+                return Err(BuildGameError::InvalidRevealedLetter(char));
+            }
+
+            if !word.contains(char) {
+                return Err(BuildGameError::RevealedLetterNotInWord(char));
+            }
+
+            if !revealed_letters.contains(&char.to_string()) {
+                revealed_letters.push(char.to_string());
+            }
+        }
+
+        let game = Game {
+            status: GameStatus::InProgress,
+            word,
+            guesses: Vec::new(),
+            revealed_letters,
+            lives: self.lives,
+            lives_remaining: self.lives,
+            rules: self.rules,
+            entry,
+            history: History::default(),
+            observers: Observers::default(),
+        };
+
+        if game.unknown_letters().is_empty() {
+            return Err(BuildGameError::NothingToGuess);
+        }
+
+        Ok(game)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        game::{CompleteGameStatus, GuessStatus, HintError, MakeGuessError},
+        words::Difficulty,
+    };
+
+    #[test]
+    fn when_building_without_a_word_then_a_random_word_is_picked() {
+        let game = GameBuilder::new().minimum_word_size(6).build().unwrap();
+        let short_game = GameBuilder::new()
+            .minimum_word_size(3)
+            .maximum_word_size(4)
+            .build()
+            .unwrap();
+
+        assert!(game.letters().len() >= 6);
+        assert!((3..=4).contains(&short_game.letters().len()));
+        assert_eq!(
+            GameBuilder::new()
+                .minimum_word_size(5)
+                .maximum_word_size(4)
+                .build()
+                .unwrap_err()
+                .to_string(),
+            "there are no words with 5 to 4 letters"
+        );
+        assert_eq!(game.lives_remaining(), 10);
+        assert_eq!(
+            game.entry().map(|entry| entry.word.clone()),
+            Some(game.letters().concat())
+        );
+    }
+
+    #[test]
+    fn when_building_with_a_word_pack_entry_then_it_is_kept_with_the_game() {
+        let entry = WordEntry {
+            word: String::from("CAT"),
+            category: String::from("animals"),
+            difficulty: Difficulty::Easy,
+            language: String::from("en"),
+            clue: Some(String::from("A small furry pet")),
+            frequency: None,
+            definition: None,
+        };
+
+        let game = GameBuilder::new().entry(entry.clone()).build().unwrap();
+        let chosen_word_game = GameBuilder::new()
+            .entry(entry.clone())
+            .word("dog")
+            .build()
+            .unwrap();
+
+        assert_eq!(game.entry(), Some(&entry));
+        assert_eq!(game.blanked_out_letters().concat(), "___");
+        assert_eq!(chosen_word_game.entry(), None);
+    }
+
+    #[test]
+    fn when_building_with_revealed_letters_then_they_are_shown_but_not_counted_as_guesses() {
+        let mut game = GameBuilder::new()
+            .word("banana")
+            .reveal("a, n")
+            .build()
+            .unwrap();
+
+        assert_eq!(game.blanked_out_letters().concat(), "_ANANA");
+        assert_eq!(game.guesses(), Vec::new());
+        assert_eq!(
+            game.make_guess("a"),
+            Err(MakeGuessError::AlreadyGuessed(String::from("A")))
+        );
+        assert_eq!(game.use_hint(), Ok(String::from("B")));
+        assert_eq!(game.status(), GameStatus::Complete(CompleteGameStatus::Won));
+    }
+
+    #[test]
+    fn when_building_with_a_phrase_then_separators_are_shown_and_only_letters_are_guessed() {
+        let mut game = GameBuilder::new()
+            .phrase("  rock'n'roll   all-nite ")
+            .build()
+            .unwrap();
+
+        assert_eq!(game.blanked_out_letters().concat(), "____'_'____ ___-____");
+
+        for letter in ["r", "o", "c", "k", "n", "l", "a", "i"] {
+            game.make_guess(letter).unwrap();
+        }
+
+        assert_eq!(game.solve("rock n roll all nite"), Ok(true));
+        assert_eq!(game.status(), GameStatus::Complete(CompleteGameStatus::Won));
+    }
+
+    #[test]
+    fn when_building_with_rules_then_hints_and_solving_cost_the_chosen_lives() {
+        let mut game = GameBuilder::new()
+            .word("crate")
+            .lives(5)
+            .rules(Rules {
+                hint_cost: 2,
+                incorrect_solve_cost: 3,
+                ..Rules::default()
+            })
+            .build()
+            .unwrap();
+
+        assert_eq!(game.solve("grate"), Ok(false));
+        assert_eq!(game.lives_remaining(), 2);
+        assert_eq!(game.use_hint(), Err(HintError::NotEnoughLives));
+        assert_eq!(game.solve("irate"), Ok(false));
+        assert_eq!(game.lives_remaining(), 0);
+        assert_eq!(
+            game.status(),
+            GameStatus::Complete(CompleteGameStatus::Lost)
+        );
+        assert_eq!(
+            game.guesses(),
+            Vec::<(String, GuessStatus)>::new(),
+            "solving shouldn't add guesses"
+        );
+    }
+
+    #[test]
+    fn when_building_with_invalid_options_then_an_error_is_returned() {
+        let build = |builder: GameBuilder| builder.build().unwrap_err();
+
+        assert_eq!(
+            build(GameBuilder::new().word("two words")),
+            BuildGameError::InvalidWord(InvalidWordError::InvalidCharacter(' '))
+        );
+        assert_eq!(
+            build(GameBuilder::new().phrase(" - ")),
+            BuildGameError::InvalidWord(InvalidWordError::Empty)
+        );
+        assert_eq!(
+            build(GameBuilder::new().word("crate").lives(0)),
+            BuildGameError::NoLives
+        );
+        assert_eq!(
+            build(GameBuilder::new().word("crate").reveal("c1")),
+            BuildGameError::InvalidRevealedLetter('1')
+        );
+        assert_eq!(
+            build(GameBuilder::new().word("crate").reveal("z")),
+            BuildGameError::RevealedLetterNotInWord('Z')
+        );
+        assert_eq!(
+            build(GameBuilder::new().word("crate").reveal("etarc")),
+            BuildGameError::NothingToGuess
+        );
+    }
+}
@@ -0,0 +1,245 @@
+use crate::game::GuessStatus;
+use std::{fmt, sync::mpsc::Sender};
+
+/// Something that happened in a [`Game`](crate::Game), sent to every subscribed
+/// [`GameObserver`] in the order it happened.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum GameEvent {
+    /// A letter, in uppercase, was guessed.
+    GuessMade {
+        /// The guessed letter.
+        letter: String,
+        /// Whether the letter is in the word.
+        status: GuessStatus,
+    },
+    /// A letter, in uppercase, was revealed by a guess, a hint or solving the word.
+    LetterRevealed {
+        /// The revealed letter.
+        letter: String,
+        /// Where the letter appears in the word, counting from 0.
+        positions: Vec<usize>,
+    },
+    /// Lives were lost to an incorrect guess, a hint or an incorrect attempt to solve the word.
+    LifeLost {
+        /// The number of lives lost.
+        lives_lost: usize,
+        /// The number of lives remaining afterwards.
+        lives_remaining: usize,
+    },
+    /// The last move was taken back with [`Game::undo`](crate::Game::undo). Any cost of the undo
+    /// follows as a [`GameEvent::LifeLost`].
+    MoveUndone {
+        /// The letters, in uppercase, that the move had added to the guesses.
+        letters: Vec<String>,
+        /// The lives the move had cost, which were given back.
+        lives_restored: usize,
+    },
+    /// An undone move was made again with [`Game::redo`](crate::Game::redo). Any lives it costs
+    /// follow as a [`GameEvent::LifeLost`].
+    MoveRedone {
+        /// The letters, in uppercase, that the move added to the guesses again.
+        letters: Vec<String>,
+    },
+    /// A hint was used to reveal a letter, in uppercase.
+    HintUsed {
+        /// The revealed letter.
+        letter: String,
+    },
+    /// Every letter in the word was guessed.
+    GameWon,
+    /// The lives ran out before the word was guessed.
+    GameLost,
+}
+
+/// Receives the [`GameEvent`]s of a [`Game`](crate::Game) it was subscribed to with
+/// [`Game::subscribe`](crate::Game::subscribe).
+///
+/// Closures taking a `&GameEvent` are observers, and so are channel senders, which receive a copy
+/// of each event.
+pub trait GameObserver: Send {
+    /// Called after each event.
+    fn notify(&mut self, event: &GameEvent);
+}
+
+impl<F> GameObserver for F
+where
+    F: FnMut(&GameEvent) + Send,
+{
+    fn notify(&mut self, event: &GameEvent) {
+        self(event)
+    }
+}
+
+impl GameObserver for Sender<GameEvent> {
+    fn notify(&mut self, event: &GameEvent) {
+        // A receiver that has gone away has stopped listening, which isn't the game's problem.
+        let _ = self.send(event.clone());
+    }
+}
+
+#[derive(Default)]
+pub(super) struct Observers(Vec<Box<dyn GameObserver>>);
+
+impl Observers {
+    pub(super) fn push(&mut self, observer: Box<dyn GameObserver>) {
+        self.0.push(observer);
+    }
+
+    pub(super) fn notify(&mut self, event: GameEvent) {
+        for observer in self.0.iter_mut() {
+            observer.notify(&event);
+        }
+    }
+}
+
+// Observers belong to the game they were subscribed to, so a copy of the game starts without any.
+impl Clone for Observers {
+    fn clone(&self) -> Self {
+        Observers::default()
+    }
+}
+
+impl fmt::Debug for Observers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} observers", self.0.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{Game, GameBuilder, Rules};
+    use std::sync::{mpsc, Arc, Mutex};
+
+    fn subscribed_game(builder: GameBuilder) -> (Game, mpsc::Receiver<GameEvent>) {
+        let mut game = builder.build().unwrap();
+        let (sender, receiver) = mpsc::channel();
+
+        game.subscribe(sender);
+
+        (game, receiver)
+    }
+
+    #[test]
+    fn when_guessing_then_guess_reveal_and_life_events_are_sent_in_order() {
+        let (mut game, receiver) = subscribed_game(GameBuilder::new().word("kayak").lives(2));
+
+        let _ = game.make_guess("k");
+        let _ = game.make_guess("k");
+        let _ = game.make_guess("z");
+
+        assert_eq!(
+            receiver.try_iter().collect::<Vec<GameEvent>>(),
+            vec![
+                GameEvent::GuessMade {
+                    letter: String::from("K"),
+                    status: GuessStatus::Correct
+                },
+                GameEvent::LetterRevealed {
+                    letter: String::from("K"),
+                    positions: vec![0, 4]
+                },
+                GameEvent::GuessMade {
+                    letter: String::from("Z"),
+                    status: GuessStatus::Incorrect
+                },
+                GameEvent::LifeLost {
+                    lives_lost: 1,
+                    lives_remaining: 1
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn when_the_game_ends_then_a_single_won_or_lost_event_is_sent() {
+        let (mut won_game, won_receiver) = subscribed_game(GameBuilder::new().word("aa"));
+        let (mut lost_game, lost_receiver) =
+            subscribed_game(GameBuilder::new().word("aa").lives(1));
+
+        let _ = won_game.make_guess("a");
+        let _ = won_game.make_guess("b");
+        let _ = lost_game.make_guess("b");
+        let _ = lost_game.make_guess("a");
+
+        assert_eq!(won_receiver.try_iter().last(), Some(GameEvent::GameWon));
+        assert_eq!(
+            lost_receiver.try_iter().collect::<Vec<GameEvent>>()[1..],
+            [
+                GameEvent::LifeLost {
+                    lives_lost: 1,
+                    lives_remaining: 0
+                },
+                GameEvent::GameLost
+            ]
+        );
+    }
+
+    #[test]
+    fn when_using_a_hint_or_solving_then_the_revealed_letters_and_lives_lost_are_sent() {
+        let (mut game, receiver) = subscribed_game(GameBuilder::new().word("ab").rules(Rules {
+            hint_cost: 2,
+            incorrect_solve_cost: 3,
+            ..Rules::default()
+        }));
+
+        let letter = game.use_hint().unwrap();
+        let other_letter = if letter == "A" { "B" } else { "A" };
+        let _ = game.solve("aa");
+        let _ = game.solve("ab");
+
+        assert_eq!(
+            receiver.try_iter().collect::<Vec<GameEvent>>(),
+            vec![
+                GameEvent::HintUsed {
+                    letter: letter.clone()
+                },
+                GameEvent::LetterRevealed {
+                    positions: vec![usize::from(letter == "B")],
+                    letter,
+                },
+                GameEvent::LifeLost {
+                    lives_lost: 2,
+                    lives_remaining: 8
+                },
+                GameEvent::LifeLost {
+                    lives_lost: 3,
+                    lives_remaining: 5
+                },
+                GameEvent::LetterRevealed {
+                    positions: vec![usize::from(other_letter == "B")],
+                    letter: String::from(other_letter),
+                },
+                GameEvent::GameWon,
+            ]
+        );
+    }
+
+    #[test]
+    fn every_observer_is_notified_and_clones_start_without_observers() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let mut game = GameBuilder::new().word("crate").build().unwrap();
+
+        for name in ["first", "second"] {
+            let events = Arc::clone(&events);
+
+            game.subscribe(move |event: &GameEvent| {
+                events.lock().unwrap().push((name, event.clone()));
+            });
+        }
+
+        let mut clone = game.clone();
+        let _ = clone.make_guess("c");
+        let _ = game.make_guess("z");
+
+        let names = events
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<&str>>();
+
+        assert_eq!(names, ["first", "second", "first", "second"]);
+    }
+}
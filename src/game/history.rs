@@ -0,0 +1,298 @@
+use crate::game::{Game, GameEvent, GameStatus, GuessStatus};
+use std::{error::Error, fmt};
+
+/// A guess, hint or attempt to solve the word that can be taken back with [`Game::undo`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Move {
+    /// The letters the move added to the guesses, in the order they were added. An incorrect
+    /// attempt to solve the word adds none.
+    pub guesses: Vec<(String, GuessStatus)>,
+    /// The lives the move cost.
+    pub lives_lost: usize,
+}
+
+impl Move {
+    /// The letters the move added to the guesses.
+    pub fn letters(&self) -> Vec<String> {
+        self.guesses
+            .iter()
+            .map(|(letter, _)| letter.clone())
+            .collect()
+    }
+}
+
+/// A move that couldn't be undone or redone.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum UndoError {
+    /// No moves have been made since the start of the game or the last undo.
+    NothingToUndo,
+    /// No moves have been undone since the last move was made.
+    NothingToRedo,
+    /// Every undo in [`Rules::undo_allowance`](crate::Rules::undo_allowance) has been used.
+    NoUndosLeft,
+    /// Paying [`Rules::undo_cost`](crate::Rules::undo_cost) would use up the last life.
+    NotEnoughLives,
+    /// The game is already over.
+    GameComplete,
+}
+
+impl fmt::Display for UndoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UndoError::NothingToUndo => write!(f, "there is no move to undo"),
+            UndoError::NothingToRedo => write!(f, "there is no move to redo"),
+            UndoError::NoUndosLeft => write!(f, "every undo for this game has been used"),
+            UndoError::NotEnoughLives => write!(f, "an undo needs more lives than remain"),
+            UndoError::GameComplete => write!(f, "the game is already over"),
+        }
+    }
+}
+
+impl Error for UndoError {}
+
+/// The result of [`Game::undo`] and [`Game::redo`], holding the move that was undone or redone.
+pub type UndoResult = Result<Move, UndoError>;
+
+#[derive(Clone, Debug, Default)]
+pub(super) struct History {
+    moves: Vec<Move>,
+    undone_moves: Vec<Move>,
+    undos_used: usize,
+}
+
+impl History {
+    pub(super) fn record(&mut self, made_move: Move) {
+        self.moves.push(made_move);
+        self.undone_moves.clear();
+    }
+}
+
+impl Game {
+    /// Takes back the last move, restoring the lives it cost, at the cost of
+    /// [`Rules::undo_cost`](crate::Rules::undo_cost) lives. Each game allows
+    /// [`Rules::undo_allowance`](crate::Rules::undo_allowance) undos, and a finished game can't be
+    /// undone.
+    pub fn undo(&mut self) -> UndoResult {
+        if self.status != GameStatus::InProgress {
+            return Err(UndoError::GameComplete);
+        }
+
+        let Some(last_move) = self.history.moves.last() else {
+            return Err(UndoError::NothingToUndo);
+        };
+
+        if self.undos_remaining() == 0 {
+            return Err(UndoError::NoUndosLeft);
+        }
+
+        if self.lives_remaining + last_move.lives_lost <= self.rules.undo_cost {
+            return Err(UndoError::NotEnoughLives);
+        }
+
+        let last_move = self
+            .history
+            .moves
+            .pop()
+            .expect("the last move should have been found");
+
+        self.guesses
+            .truncate(self.guesses.len() - last_move.guesses.len());
+        self.lives_remaining += last_move.lives_lost;
+        self.history.undos_used += 1;
+        self.observers.notify(GameEvent::MoveUndone {
+            letters: last_move.letters(),
+            lives_restored: last_move.lives_lost,
+        });
+        self.lose_lives(self.rules.undo_cost);
+
+        self.history.undone_moves.push(last_move.clone());
+
+        Ok(last_move)
+    }
+
+    /// Makes the last undone move again, costing the same lives it did the first time. Redoing
+    /// doesn't give back the undo or its cost.
+    pub fn redo(&mut self) -> UndoResult {
+        if self.status != GameStatus::InProgress {
+            return Err(UndoError::GameComplete);
+        }
+
+        let Some(undone_move) = self.history.undone_moves.pop() else {
+            return Err(UndoError::NothingToRedo);
+        };
+
+        self.guesses.extend(undone_move.guesses.iter().cloned());
+        self.observers.notify(GameEvent::MoveRedone {
+            letters: undone_move.letters(),
+        });
+        let lives_lost = self.lose_lives(undone_move.lives_lost);
+
+        let redone_move = Move {
+            guesses: undone_move.guesses,
+            lives_lost,
+        };
+
+        self.history.moves.push(redone_move.clone());
+
+        self.update_status();
+
+        Ok(redone_move)
+    }
+
+    /// The number of undos left in this game.
+    pub fn undos_remaining(&self) -> usize {
+        self.rules
+            .undo_allowance
+            .saturating_sub(self.history.undos_used)
+    }
+
+    /// Whether there is a move that [`Game::undo`] would take back, ignoring the allowance and
+    /// cost.
+    pub fn can_undo(&self) -> bool {
+        self.status == GameStatus::InProgress && !self.history.moves.is_empty()
+    }
+
+    /// Whether there is an undone move that [`Game::redo`] would make again.
+    pub fn can_redo(&self) -> bool {
+        self.status == GameStatus::InProgress && !self.history.undone_moves.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{CompleteGameStatus, GameBuilder, Rules};
+
+    fn create_game(word: &str, lives: usize, rules: Rules) -> Game {
+        GameBuilder::new()
+            .word(word)
+            .lives(lives)
+            .rules(rules)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn when_undoing_a_guess_then_it_is_removed_and_its_life_is_restored() {
+        let mut game = create_game("crate", 5, Rules::default());
+
+        let _ = game.make_guess("c");
+        let _ = game.make_guess("z");
+
+        assert_eq!(
+            game.undo(),
+            Ok(Move {
+                guesses: vec![(String::from("Z"), GuessStatus::Incorrect)],
+                lives_lost: 1
+            })
+        );
+        assert_eq!(game.guess_letters(), vec![String::from("C")]);
+        assert_eq!(game.lives_remaining(), 5);
+        assert_eq!(game.make_guess("z").map(|_| ()), Ok(()));
+    }
+
+    #[test]
+    fn when_redoing_an_undone_guess_then_it_is_made_again() {
+        let mut game = create_game("crate", 5, Rules::default());
+
+        let _ = game.make_guess("z");
+        let _ = game.undo();
+
+        assert!(game.can_redo());
+        assert_eq!(
+            game.redo().map(|undone_move| undone_move.letters()),
+            Ok(vec![String::from("Z")])
+        );
+        assert_eq!(game.lives_remaining(), 4);
+        assert_eq!(game.redo(), Err(UndoError::NothingToRedo));
+    }
+
+    #[test]
+    fn when_making_a_new_move_then_undone_moves_can_no_longer_be_redone() {
+        let mut game = create_game("crate", 5, Rules::default());
+
+        let _ = game.make_guess("z");
+        let _ = game.undo();
+        let _ = game.make_guess("c");
+
+        assert!(!game.can_redo());
+        assert_eq!(game.redo(), Err(UndoError::NothingToRedo));
+    }
+
+    #[test]
+    fn when_the_undo_allowance_is_used_then_no_more_undos_are_allowed() {
+        let mut game = create_game(
+            "crate",
+            5,
+            Rules {
+                undo_allowance: 1,
+                ..Rules::default()
+            },
+        );
+
+        let _ = game.make_guess("z");
+        let _ = game.make_guess("y");
+
+        assert!(game.undo().is_ok());
+        assert_eq!(game.undos_remaining(), 0);
+        assert!(game.can_undo());
+        assert_eq!(game.undo(), Err(UndoError::NoUndosLeft));
+        assert_eq!(game.redo().map(|_| ()), Ok(()));
+        assert_eq!(game.undo(), Err(UndoError::NoUndosLeft));
+    }
+
+    #[test]
+    fn when_undoing_costs_lives_then_the_cost_is_paid_unless_it_would_use_the_last_life() {
+        let mut game = create_game(
+            "crate",
+            3,
+            Rules {
+                undo_cost: 2,
+                ..Rules::default()
+            },
+        );
+
+        let _ = game.make_guess("z");
+        assert!(game.undo().is_ok());
+        assert_eq!(game.lives_remaining(), 1);
+
+        let _ = game.make_guess("c");
+        assert_eq!(game.undo(), Err(UndoError::NotEnoughLives));
+    }
+
+    #[test]
+    fn when_undoing_a_hint_or_a_wrong_solve_attempt_then_its_cost_is_restored() {
+        let mut game = create_game("crate", 5, Rules::default());
+
+        let _ = game.solve("grate");
+        let _ = game.use_hint();
+
+        assert_eq!(game.lives_remaining(), 3);
+        assert_eq!(game.undo().map(|undone_move| undone_move.lives_lost), Ok(1));
+        assert!(game.guesses().is_empty());
+        assert_eq!(
+            game.undo(),
+            Ok(Move {
+                guesses: Vec::new(),
+                lives_lost: 1
+            })
+        );
+        assert_eq!(game.lives_remaining(), 5);
+        assert_eq!(game.undo(), Err(UndoError::NothingToUndo));
+    }
+
+    #[test]
+    fn when_the_game_is_complete_then_nothing_can_be_undone() {
+        let mut game = create_game("a", 1, Rules::default());
+
+        let _ = game.make_guess("z");
+
+        assert_eq!(
+            game.status(),
+            GameStatus::Complete(CompleteGameStatus::Lost)
+        );
+        assert!(!game.can_undo());
+        assert_eq!(game.undo(), Err(UndoError::GameComplete));
+    }
+}
@@ -0,0 +1,339 @@
+use std::{env, fmt};
+
+mod en;
+mod fr;
+
+const LOCALE_VARIABLES: [&str; 4] = ["HANGMAN_LANG", "LC_ALL", "LC_MESSAGES", "LANG"];
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) enum Locale {
+    #[default]
+    English,
+    French,
+}
+
+/// A message whose wording depends on a count. `{count}` in either form is replaced by the count.
+#[derive(Debug)]
+pub(crate) struct Plural {
+    pub(crate) one: &'static str,
+    pub(crate) other: &'static str,
+}
+
+/// Every player-facing string, so that a new language only has to fill in one of these. Words in
+/// braces, such as `{guess}`, are replaced using [`fill`].
+// Each game runner only uses the strings it shows.
+#[allow(dead_code)]
+#[derive(Debug)]
+pub(crate) struct Catalogue {
+    pub(crate) and: &'static str,
+    pub(crate) none: &'static str,
+    pub(crate) quoted: &'static str,
+    pub(crate) yes: &'static str,
+    pub(crate) no: &'static str,
+    pub(crate) on: &'static str,
+    pub(crate) off: &'static str,
+    pub(crate) hangman: &'static str,
+    pub(crate) welcome: &'static str,
+    pub(crate) play_again: &'static str,
+    pub(crate) category_label: &'static str,
+    pub(crate) clue_label: &'static str,
+
+    pub(crate) rules: &'static str,
+    pub(crate) rule_lives: Plural,
+    pub(crate) rule_blanked_out_word: &'static str,
+    pub(crate) rule_incorrect_guess: &'static str,
+    pub(crate) rule_no_lives: &'static str,
+    pub(crate) rule_all_letters: &'static str,
+    pub(crate) rule_undos: Plural,
+    pub(crate) rule_undo_cost: Plural,
+
+    pub(crate) guess_correct: &'static str,
+    pub(crate) guess_incorrect: &'static str,
+    pub(crate) guess_empty: &'static str,
+    pub(crate) guess_too_long: &'static str,
+    pub(crate) guess_invalid: &'static str,
+    pub(crate) guess_already_guessed: &'static str,
+    pub(crate) game_already_over: &'static str,
+
+    pub(crate) undo_done: Plural,
+    pub(crate) redo_done: &'static str,
+    pub(crate) solve_attempt_move: &'static str,
+    pub(crate) nothing_to_undo: &'static str,
+    pub(crate) nothing_to_redo: &'static str,
+    pub(crate) no_undos_left: &'static str,
+    pub(crate) undo_not_enough_lives: &'static str,
+
+    pub(crate) ordinals: [&'static str; 20],
+    pub(crate) letter_count: Plural,
+    pub(crate) no_letters_revealed: &'static str,
+    pub(crate) revealed_letter: Plural,
+    pub(crate) lives_left: Plural,
+    pub(crate) no_letters_guessed: &'static str,
+    pub(crate) guessed_letters: Plural,
+    pub(crate) won_description: Plural,
+    pub(crate) lost_description: &'static str,
+    pub(crate) definition: &'static str,
+    pub(crate) clue_definition: &'static str,
+    pub(crate) example: &'static str,
+    pub(crate) daily_won: Plural,
+    pub(crate) daily_lost: &'static str,
+    pub(crate) daily_already_played: &'static str,
+    pub(crate) daily_not_saved: &'static str,
+    pub(crate) no_words: &'static str,
+    pub(crate) unknown_category: &'static str,
+    pub(crate) word_sizes_crossed: &'static str,
+    pub(crate) invalid_weights: &'static str,
+    pub(crate) no_words_to_pick: &'static str,
+    pub(crate) no_matching_words: &'static str,
+    pub(crate) filter_words: &'static str,
+    pub(crate) filter_language: &'static str,
+    pub(crate) filter_category: &'static str,
+    pub(crate) filter_length: Plural,
+    pub(crate) filter_length_range: &'static str,
+    pub(crate) filter_minimum_length: &'static str,
+    pub(crate) filter_unique_letters: Plural,
+    pub(crate) filter_excluded_letters: &'static str,
+    pub(crate) history_not_loaded: &'static str,
+
+    pub(crate) welcome_to: &'static str,
+    pub(crate) intro_help: &'static str,
+    pub(crate) word_to_guess: &'static str,
+    pub(crate) guess_prompt: &'static str,
+    pub(crate) plain_guess_prompt: &'static str,
+    pub(crate) lives_remaining: Plural,
+    pub(crate) no_previous_guesses: &'static str,
+    pub(crate) previous_guesses: &'static str,
+    pub(crate) won: Plural,
+    pub(crate) lost: &'static str,
+    pub(crate) play_again_choices: &'static str,
+    pub(crate) yes_answer: &'static str,
+    pub(crate) no_answer: &'static str,
+    pub(crate) thanks_for_playing: &'static str,
+    pub(crate) session_summary: Plural,
+    pub(crate) unreadable_input: &'static str,
+    pub(crate) new_game_started: &'static str,
+    pub(crate) setting_changed: &'static str,
+    pub(crate) hint: &'static str,
+    pub(crate) hint_not_enough_lives: &'static str,
+    pub(crate) solve_correct: &'static str,
+    pub(crate) solve_incorrect: &'static str,
+    pub(crate) solve_invalid: &'static str,
+    pub(crate) only_during_a_game: &'static str,
+    pub(crate) commands: &'static str,
+    pub(crate) guess_a_letter: &'static str,
+    /// In the same order as the stdio runner's `COMMANDS`.
+    pub(crate) command_descriptions: [&'static str; 10],
+    pub(crate) history_tip: &'static str,
+    pub(crate) settings_tip: &'static str,
+    pub(crate) played: &'static str,
+    pub(crate) won_count: &'static str,
+    pub(crate) lost_count: &'static str,
+    pub(crate) unknown_command: &'static str,
+    pub(crate) command_usage: &'static str,
+    pub(crate) command_takes_no_arguments: &'static str,
+    pub(crate) share_result: &'static str,
+
+    pub(crate) new_game: &'static str,
+    pub(crate) game_modes: &'static str,
+    pub(crate) statistics: &'static str,
+    pub(crate) settings: &'static str,
+    pub(crate) help: &'static str,
+    pub(crate) quit: &'static str,
+    pub(crate) classic: &'static str,
+    pub(crate) classic_description: &'static str,
+    pub(crate) sudden_death: &'static str,
+    pub(crate) sudden_death_description: &'static str,
+    pub(crate) daily: &'static str,
+    pub(crate) daily_description: &'static str,
+    pub(crate) active_game_mode: &'static str,
+    pub(crate) game_mode: &'static str,
+    pub(crate) lives: &'static str,
+    pub(crate) minimum_word_length: &'static str,
+    pub(crate) maximum_word_length: &'static str,
+    pub(crate) exact_word_length: &'static str,
+    pub(crate) unique_letters: &'static str,
+    pub(crate) reduced_motion: &'static str,
+    pub(crate) accessible_mode: &'static str,
+    pub(crate) undo_allowance: &'static str,
+    pub(crate) undo_cost: &'static str,
+    pub(crate) confirm_guesses: &'static str,
+    pub(crate) category: &'static str,
+    pub(crate) any_category: &'static str,
+    pub(crate) difficulty: &'static str,
+    pub(crate) easy: &'static str,
+    pub(crate) medium: &'static str,
+    pub(crate) hard: &'static str,
+    pub(crate) no_limit: &'static str,
+    pub(crate) exit: &'static str,
+    pub(crate) back: &'static str,
+    pub(crate) menu: &'static str,
+    pub(crate) move_selection: &'static str,
+    pub(crate) select: &'static str,
+    pub(crate) change: &'static str,
+    pub(crate) make_guess: &'static str,
+    pub(crate) select_letter: &'static str,
+    pub(crate) confirm: &'static str,
+    pub(crate) letter_selected: &'static str,
+    pub(crate) undo: &'static str,
+    pub(crate) redo: &'static str,
+    pub(crate) copy_result: &'static str,
+    pub(crate) result_copied: &'static str,
+    pub(crate) close_help: &'static str,
+    pub(crate) controls: &'static str,
+    pub(crate) gallows: &'static str,
+    pub(crate) current_game: &'static str,
+    pub(crate) remaining_letters: &'static str,
+    pub(crate) guesses: &'static str,
+    pub(crate) header_statistics: &'static str,
+    pub(crate) lives_remaining_label: Plural,
+    pub(crate) you_won: &'static str,
+    pub(crate) you_lost: &'static str,
+    pub(crate) games_played: &'static str,
+    pub(crate) games_won: &'static str,
+    pub(crate) games_lost: &'static str,
+    pub(crate) win_rate: &'static str,
+    pub(crate) accessible_header: &'static str,
+    pub(crate) last_guess: &'static str,
+    pub(crate) play_again_selected: &'static str,
+    pub(crate) controls_sentence: &'static str,
+    pub(crate) control_sentence: &'static str,
+}
+
+impl Locale {
+    pub(crate) const ALL: [Locale; 2] = [Locale::English, Locale::French];
+
+    pub(crate) fn code(self) -> &'static str {
+        match self {
+            Locale::English => "en",
+            Locale::French => "fr",
+        }
+    }
+
+    /// Reads a language code or a POSIX locale such as `fr_FR.UTF-8`.
+    pub(crate) fn from_code(code: &str) -> Option<Self> {
+        let language = code
+            .split(['_', '-', '.', '@'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+
+        Locale::ALL
+            .into_iter()
+            .find(|locale| locale.code() == language)
+    }
+
+    /// Uses the first locale variable that is set, falling back to English if its language isn't
+    /// supported.
+    pub(crate) fn from_env() -> Self {
+        LOCALE_VARIABLES
+            .iter()
+            .filter_map(|variable| env::var(variable).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|value| Locale::from_code(&value))
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn catalogue(self) -> &'static Catalogue {
+        match self {
+            Locale::English => &en::CATALOGUE,
+            Locale::French => &fr::CATALOGUE,
+        }
+    }
+
+    /// Picks the form of a plural message for the count without filling it in.
+    pub(crate) fn select(self, plural: &Plural, count: usize) -> &'static str {
+        let is_singular = match self {
+            Locale::English => count == 1,
+            Locale::French => count <= 1,
+        };
+
+        if is_singular {
+            plural.one
+        } else {
+            plural.other
+        }
+    }
+
+    pub(crate) fn plural(self, plural: &Plural, count: usize) -> String {
+        fill(self.select(plural, count), &[("count", &count)])
+    }
+
+    pub(crate) fn ordinal(self, number: usize) -> String {
+        if let Some(ordinal) = self.catalogue().ordinals.get(number.wrapping_sub(1)) {
+            return ordinal.to_string();
+        }
+
+        let suffix = match self {
+            Locale::English => match (number % 10, number % 100) {
+                (_, 11..=13) => "th",
+                (1, _) => "st",
+                (2, _) => "nd",
+                (3, _) => "rd",
+                _ => "th",
+            },
+            Locale::French => "e",
+        };
+
+        format!("{}{}", number, suffix)
+    }
+}
+
+pub(crate) fn fill(template: &str, arguments: &[(&str, &dyn fmt::Display)]) -> String {
+    arguments
+        .iter()
+        .fold(template.to_string(), |text, (name, value)| {
+            text.replace(&format!("{{{}}}", name), &value.to_string())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locales_are_read_from_language_codes_and_posix_locales() {
+        assert_eq!(Locale::from_code("fr"), Some(Locale::French));
+        assert_eq!(Locale::from_code("fr_CA.UTF-8"), Some(Locale::French));
+        assert_eq!(Locale::from_code("EN-gb"), Some(Locale::English));
+        assert_eq!(Locale::from_code("C"), None);
+        assert_eq!(Locale::from_code("de_DE"), None);
+    }
+
+    #[test]
+    fn plurals_follow_the_rules_of_each_language() {
+        let lives_left = &Locale::English.catalogue().lives_left;
+
+        assert_eq!(Locale::English.plural(lives_left, 0), "0 lives left");
+        assert_eq!(Locale::English.plural(lives_left, 1), "1 life left");
+        assert_eq!(Locale::English.plural(lives_left, 2), "2 lives left");
+
+        let lives_left = &Locale::French.catalogue().lives_left;
+
+        assert_eq!(Locale::French.plural(lives_left, 0), "0 vie restante");
+        assert_eq!(Locale::French.plural(lives_left, 1), "1 vie restante");
+        assert_eq!(Locale::French.plural(lives_left, 2), "2 vies restantes");
+    }
+
+    #[test]
+    fn ordinals_past_twentieth_use_numeric_suffixes() {
+        assert_eq!(Locale::English.ordinal(1), "first");
+        assert_eq!(Locale::English.ordinal(20), "twentieth");
+        assert_eq!(Locale::English.ordinal(21), "21st");
+        assert_eq!(Locale::English.ordinal(22), "22nd");
+        assert_eq!(Locale::English.ordinal(23), "23rd");
+        assert_eq!(Locale::English.ordinal(111), "111th");
+        assert_eq!(Locale::French.ordinal(1), "première");
+        assert_eq!(Locale::French.ordinal(21), "21e");
+    }
+
+    #[test]
+    fn filling_a_template_replaces_each_named_placeholder() {
+        assert_eq!(
+            fill(
+                "{played} played, {won} won",
+                &[("played", &3), ("won", &"two")]
+            ),
+            "3 played, two won"
+        );
+    }
+}
@@ -0,0 +1,258 @@
+use super::{Catalogue, Plural};
+
+pub(super) const CATALOGUE: Catalogue = Catalogue {
+    and: "and",
+    none: "none",
+    quoted: "\"{text}\"",
+    yes: "Yes",
+    no: "No",
+    on: "On",
+    off: "Off",
+    hangman: "Hangman",
+    welcome: "Welcome to Hangman!",
+    play_again: "Would you like to play again?",
+    category_label: "Category: {category}",
+    clue_label: "Clue: {clue}",
+
+    rules: "Rules",
+    rule_lives: Plural {
+        one: "You get {count} life.",
+        other: "You get {count} lives.",
+    },
+    rule_blanked_out_word:
+        "You will be shown a blanked out word and asked to guess a letter from the word.",
+    rule_incorrect_guess: "If your guess is incorrect, you lose a life.",
+    rule_no_lives: "If your lives hit 0, you lose the game.",
+    rule_all_letters: "If you guess all the letters in the word, you win the game.",
+    rule_undos: Plural {
+        one: "You can take back {count} move per game.",
+        other: "You can take back {count} moves per game.",
+    },
+    rule_undo_cost: Plural {
+        one: "Taking back a move costs {count} life.",
+        other: "Taking back a move costs {count} lives.",
+    },
+
+    guess_correct: "\u{2713} Awesome! \"{guess}\" is in the word! Nice job!",
+    guess_incorrect: "\u{2717} Sorry! \"{guess}\" is not in the word!",
+    guess_empty: "\u{2717} Your guess was empty!",
+    guess_too_long: "\u{2717} You entered more than one character! That's cheating!",
+    guess_invalid:
+        "\u{2717} You entered an invalid character! I don't know what to do with \"{guess}\".",
+    guess_already_guessed: "You've already guessed \"{guess}\"!",
+    game_already_over: "\u{2717} The game is already over!",
+
+    undo_done: Plural {
+        one: "\u{2713} Took back {move}. You have {count} undo left in this game.",
+        other: "\u{2713} Took back {move}. You have {count} undos left in this game.",
+    },
+    redo_done: "\u{2713} Made {move} again.",
+    solve_attempt_move: "your attempt to solve the word",
+    nothing_to_undo: "\u{2717} There's nothing to take back!",
+    nothing_to_redo: "\u{2717} There's nothing to make again!",
+    no_undos_left: "\u{2717} You've used all your undos for this game!",
+    undo_not_enough_lives: "\u{2717} You don't have enough lives left to take back a move!",
+
+    ordinals: [
+        "first",
+        "second",
+        "third",
+        "fourth",
+        "fifth",
+        "sixth",
+        "seventh",
+        "eighth",
+        "ninth",
+        "tenth",
+        "eleventh",
+        "twelfth",
+        "thirteenth",
+        "fourteenth",
+        "fifteenth",
+        "sixteenth",
+        "seventeenth",
+        "eighteenth",
+        "nineteenth",
+        "twentieth",
+    ],
+    letter_count: Plural {
+        one: "{count} letter",
+        other: "{count} letters",
+    },
+    no_letters_revealed: "no letters revealed yet",
+    revealed_letter: Plural {
+        one: "{positions} letter is {letter}",
+        other: "{positions} letters are {letter}",
+    },
+    lives_left: Plural {
+        one: "{count} life left",
+        other: "{count} lives left",
+    },
+    no_letters_guessed: "No letters guessed yet.",
+    guessed_letters: Plural {
+        one: "You have guessed {count} letter. In the word: {correct}. Not in the word: {incorrect}.",
+        other:
+            "You have guessed {count} letters. In the word: {correct}. Not in the word: {incorrect}.",
+    },
+    won_description: Plural {
+        one: "You won with {count} life left! The word was {word}.",
+        other: "You won with {count} lives left! The word was {word}.",
+    },
+    lost_description: "You ran out of lives. The word was {word}.",
+    definition: "{word} ({part_of_speech}): {definition}",
+    clue_definition: "{word}: {clue}",
+    example: "For example: {example}",
+    daily_won: Plural {
+        one: "Hangman {date}: solved with {count}/{lives} life left",
+        other: "Hangman {date}: solved with {count}/{lives} lives left",
+    },
+    daily_lost: "Hangman {date}: not solved",
+    daily_already_played: "You've already played today's word. Come back tomorrow for a new one!",
+    daily_not_saved: "Today's result couldn't be saved: {error}",
+    no_words: "No word could be picked: {error}. Try a smaller minimum word size, a larger maximum, another category or more word packs.",
+    no_words_to_pick: "there are no words to pick from",
+    no_matching_words: "there are no {words}",
+    filter_words: "words",
+    filter_language: "in \"{language}\"",
+    filter_category: "in the \"{category}\" category",
+    filter_length: Plural {
+        one: "with {count} letter",
+        other: "with {count} letters",
+    },
+    filter_length_range: "with {minimum} to {maximum} letters",
+    filter_minimum_length: "with at least {minimum} letters",
+    filter_unique_letters: Plural {
+        one: "and {count} different letter",
+        other: "and {count} different letters",
+    },
+    filter_excluded_letters: "without the letters {letters}",
+    unknown_category: "unknown category \"{category}\", expected one of: {categories}",
+    word_sizes_crossed: "\"minimum_word_size\" ({minimum}) can't be more than \"maximum_word_size\" ({maximum})",
+    invalid_weights: "No word could be picked because the word frequencies are too large or small to weigh the words by. Try another difficulty or check the frequencies in your word packs.",
+    history_not_loaded: "The recently played words couldn't be read, so they may come up again soon: {error}",
+
+    welcome_to: "Welcome to",
+    intro_help: "Type \"/help\" at any time to see the rules and commands, or \"/quit\" to leave.",
+    word_to_guess: "The word for you to guess is:",
+    guess_prompt: "Please guess a letter, and make it a good one!",
+    plain_guess_prompt: "Please guess a letter.",
+    lives_remaining: Plural {
+        one: "You have {count} life remaining.",
+        other: "You have {count} lives remaining.",
+    },
+    no_previous_guesses: "No previous guesses.",
+    previous_guesses: "Previous guesses: {guesses}",
+    won: Plural {
+        one: "Well done! You guessed the word with {count} guess remaining!",
+        other: "Well done! You guessed the word with {count} guesses remaining!",
+    },
+    lost: "Oh no! You ran out of lives! I'll tell you what is was though:",
+    play_again_choices: "(y/n)",
+    yes_answer: "y",
+    no_answer: "n",
+    thanks_for_playing: "Thanks for playing!",
+    session_summary: Plural {
+        one: "You played {count} game, won {won} and lost {lost}.",
+        other: "You played {count} games, won {won} and lost {lost}.",
+    },
+    unreadable_input: "\u{2717} Sorry, I couldn't read that. Please try again.",
+    new_game_started: "Giving up on this word. Here's a new one!",
+    setting_changed: "\u{2713} \"{name}\" is now {value}. This applies from the next game.",
+    hint: "\u{2713} Here's a hint: \"{letter}\" is in the word. That cost you a life!",
+    hint_not_enough_lives: "\u{2717} You need more than one life left to use a hint!",
+    solve_correct: "\u{2713} Amazing! \"{word}\" is the word!",
+    solve_incorrect: "\u{2717} Sorry! The word isn't \"{word}\"!",
+    solve_invalid: "\u{2717} Words only contain the letters A-Z, so it can't be \"{word}\".",
+    only_during_a_game: "\u{2717} That command only works during a game.",
+    commands: "Commands",
+    guess_a_letter: "Guess a letter",
+    command_descriptions: [
+        "Reveal a letter at the cost of a life",
+        "Guess the whole word, losing a life if it's wrong",
+        "Take back your last move",
+        "Make the last move you took back again",
+        "Show the games won and lost this session",
+        "Give up on this word and start a new game",
+        "Show the settings",
+        "Change a setting, starting from the next game",
+        "Show this help",
+        "Quit the game",
+    ],
+    history_tip: "Use the up and down arrows to bring back earlier input.",
+    settings_tip: "Change one with \"/settings NAME VALUE\", e.g. \"/settings lives 8\".",
+    played: "Played",
+    won_count: "Won",
+    lost_count: "Lost",
+    unknown_command: "I don't know the command \"{command}\". Type /help to see the commands.",
+    command_usage: "Usage: {usage}",
+    command_takes_no_arguments: "{command} doesn't take any arguments.",
+    share_result: "Share your result:",
+
+    new_game: "New Game",
+    game_modes: "Game Modes",
+    statistics: "Statistics",
+    settings: "Settings",
+    help: "Help",
+    quit: "Quit",
+    classic: "Classic",
+    classic_description: "Play with the number of lives chosen in the settings.",
+    sudden_death: "Sudden Death",
+    sudden_death_description: "A single wrong guess ends the game.",
+    daily: "Word of the Day",
+    daily_description: "Everyone plays the same word today, and you only get one try.",
+    active_game_mode: "{mode} (active)",
+    game_mode: "Game Mode",
+    lives: "Lives",
+    minimum_word_length: "Minimum word length",
+    maximum_word_length: "Maximum word length",
+    exact_word_length: "Exact word length",
+    unique_letters: "Different letters",
+    reduced_motion: "Reduced motion",
+    accessible_mode: "Accessible mode",
+    undo_allowance: "Undos per game",
+    undo_cost: "Lives per undo",
+    confirm_guesses: "Confirm guesses",
+    category: "Category",
+    any_category: "Any",
+    difficulty: "Difficulty",
+    easy: "Easy",
+    medium: "Medium",
+    hard: "Hard",
+    no_limit: "Any",
+    exit: "Exit",
+    back: "Back",
+    menu: "Menu",
+    move_selection: "Move",
+    select: "Select",
+    change: "Change",
+    make_guess: "Make Guess",
+    select_letter: "Select Letter",
+    confirm: "Confirm",
+    letter_selected: "Press {key} to guess \"{letter}\", or type another letter.",
+    undo: "Undo",
+    redo: "Redo",
+    copy_result: "Copy Result",
+    result_copied: "Result copied to the clipboard.",
+    close_help: "Close Help",
+    controls: "Controls",
+    gallows: "Gallows",
+    current_game: "Current Game",
+    remaining_letters: "Remaining Letters",
+    guesses: "Guesses",
+    header_statistics: "Played: {played}, Won: {won}, Lost: {lost}",
+    lives_remaining_label: Plural {
+        one: "life remaining",
+        other: "lives remaining",
+    },
+    you_won: "You Won!",
+    you_lost: "You Lost...",
+    games_played: "Games played: {count}",
+    games_won: "Games won: {count}",
+    games_lost: "Games lost: {count}",
+    win_rate: "Win rate: {rate}%",
+    accessible_header: "Hangman. Played {played}, won {won}, lost {lost}.",
+    last_guess: "Last guess: {message}",
+    play_again_selected: "Would you like to play again? {choice} is selected.",
+    controls_sentence: "Controls: {controls}.",
+    control_sentence: "{key} to {action}",
+};
@@ -0,0 +1,263 @@
+use super::{Catalogue, Plural};
+
+pub(super) const CATALOGUE: Catalogue = Catalogue {
+    and: "et",
+    none: "aucune",
+    quoted: "« {text} »",
+    yes: "Oui",
+    no: "Non",
+    on: "Activé",
+    off: "Désactivé",
+    hangman: "Pendu",
+    welcome: "Bienvenue au jeu du pendu !",
+    play_again: "Voulez-vous rejouer ?",
+    category_label: "Catégorie : {category}",
+    clue_label: "Indice : {clue}",
+
+    rules: "Règles",
+    rule_lives: Plural {
+        one: "Vous avez {count} vie.",
+        other: "Vous avez {count} vies.",
+    },
+    rule_blanked_out_word:
+        "Un mot masqué vous sera montré et vous devrez deviner une de ses lettres.",
+    rule_incorrect_guess: "Si votre proposition est fausse, vous perdez une vie.",
+    rule_no_lives: "Si vous n'avez plus de vies, vous perdez la partie.",
+    rule_all_letters: "Si vous trouvez toutes les lettres du mot, vous gagnez la partie.",
+    rule_undos: Plural {
+        one: "Vous pouvez annuler {count} coup par partie.",
+        other: "Vous pouvez annuler {count} coups par partie.",
+    },
+    rule_undo_cost: Plural {
+        one: "Annuler un coup coûte {count} vie.",
+        other: "Annuler un coup coûte {count} vies.",
+    },
+
+    guess_correct: "\u{2713} Génial ! « {guess} » est dans le mot ! Bien joué !",
+    guess_incorrect: "\u{2717} Désolé ! « {guess} » n'est pas dans le mot !",
+    guess_empty: "\u{2717} Votre proposition était vide !",
+    guess_too_long: "\u{2717} Vous avez saisi plus d'un caractère ! C'est de la triche !",
+    guess_invalid:
+        "\u{2717} Vous avez saisi un caractère invalide ! Je ne sais pas quoi faire de « {guess} ».",
+    guess_already_guessed: "Vous avez déjà proposé « {guess} » !",
+    game_already_over: "\u{2717} La partie est déjà terminée !",
+
+    undo_done: Plural {
+        one: "\u{2713} Coup annulé : {move}. Il vous reste {count} annulation pour cette partie.",
+        other:
+            "\u{2713} Coup annulé : {move}. Il vous reste {count} annulations pour cette partie.",
+    },
+    redo_done: "\u{2713} Coup rétabli : {move}.",
+    solve_attempt_move: "votre tentative de trouver le mot",
+    nothing_to_undo: "\u{2717} Il n'y a rien à annuler !",
+    nothing_to_redo: "\u{2717} Il n'y a rien à rétablir !",
+    no_undos_left: "\u{2717} Vous avez utilisé toutes vos annulations pour cette partie !",
+    undo_not_enough_lives: "\u{2717} Il ne vous reste pas assez de vies pour annuler un coup !",
+
+    ordinals: [
+        "première",
+        "deuxième",
+        "troisième",
+        "quatrième",
+        "cinquième",
+        "sixième",
+        "septième",
+        "huitième",
+        "neuvième",
+        "dixième",
+        "onzième",
+        "douzième",
+        "treizième",
+        "quatorzième",
+        "quinzième",
+        "seizième",
+        "dix-septième",
+        "dix-huitième",
+        "dix-neuvième",
+        "vingtième",
+    ],
+    letter_count: Plural {
+        one: "{count} lettre",
+        other: "{count} lettres",
+    },
+    no_letters_revealed: "aucune lettre révélée pour l'instant",
+    revealed_letter: Plural {
+        one: "la {positions} lettre est {letter}",
+        other: "les {positions} lettres sont {letter}",
+    },
+    lives_left: Plural {
+        one: "{count} vie restante",
+        other: "{count} vies restantes",
+    },
+    no_letters_guessed: "Aucune lettre proposée pour l'instant.",
+    guessed_letters: Plural {
+        one: "Vous avez proposé {count} lettre. Dans le mot : {correct}. Pas dans le mot : {incorrect}.",
+        other:
+            "Vous avez proposé {count} lettres. Dans le mot : {correct}. Pas dans le mot : {incorrect}.",
+    },
+    won_description: Plural {
+        one: "Vous avez gagné avec {count} vie restante ! Le mot était {word}.",
+        other: "Vous avez gagné avec {count} vies restantes ! Le mot était {word}.",
+    },
+    lost_description: "Vous n'avez plus de vies. Le mot était {word}.",
+    definition: "{word} ({part_of_speech}) : {definition}",
+    clue_definition: "{word} : {clue}",
+    example: "Par exemple : {example}",
+    daily_won: Plural {
+        one: "Pendu du {date} : trouvé avec {count}/{lives} vie restante",
+        other: "Pendu du {date} : trouvé avec {count}/{lives} vies restantes",
+    },
+    daily_lost: "Pendu du {date} : pas trouvé",
+    daily_already_played: "Vous avez déjà joué le mot du jour. Revenez demain pour un nouveau mot !",
+    daily_not_saved: "Le résultat du jour n'a pas pu être enregistré : {error}",
+    no_words: "Aucun mot n'a pu être choisi : {error}. Essayez une taille minimale plus petite, une taille maximale plus grande, une autre catégorie ou d'autres paquets de mots.",
+    no_words_to_pick: "il n'y a aucun mot à choisir",
+    no_matching_words: "il n'y a pas de {words}",
+    filter_words: "mots",
+    filter_language: "en « {language} »",
+    filter_category: "de la catégorie « {category} »",
+    filter_length: Plural {
+        one: "de {count} lettre",
+        other: "de {count} lettres",
+    },
+    filter_length_range: "de {minimum} à {maximum} lettres",
+    filter_minimum_length: "d'au moins {minimum} lettres",
+    filter_unique_letters: Plural {
+        one: "et {count} lettre différente",
+        other: "et {count} lettres différentes",
+    },
+    filter_excluded_letters: "sans les lettres {letters}",
+    unknown_category: "catégorie « {category} » inconnue, choisissez parmi : {categories}",
+    word_sizes_crossed: "« minimum_word_size » ({minimum}) ne peut pas dépasser « maximum_word_size » ({maximum})",
+    invalid_weights: "Aucun mot n'a pu être choisi, car les fréquences des mots sont trop grandes ou trop petites pour les pondérer. Essayez une autre difficulté ou vérifiez les fréquences de vos paquets de mots.",
+    history_not_loaded: "Les mots joués récemment n'ont pas pu être lus, ils pourraient donc revenir bientôt : {error}",
+
+    welcome_to: "Bienvenue au",
+    intro_help:
+        "Tapez « /help » à tout moment pour voir les règles et les commandes, ou « /quit » pour partir.",
+    word_to_guess: "Le mot à deviner est :",
+    guess_prompt: "Proposez une lettre, et choisissez bien !",
+    plain_guess_prompt: "Proposez une lettre.",
+    lives_remaining: Plural {
+        one: "Il vous reste {count} vie.",
+        other: "Il vous reste {count} vies.",
+    },
+    no_previous_guesses: "Aucune proposition pour l'instant.",
+    previous_guesses: "Propositions précédentes : {guesses}",
+    won: Plural {
+        one: "Bravo ! Vous avez trouvé le mot avec {count} essai restant !",
+        other: "Bravo ! Vous avez trouvé le mot avec {count} essais restants !",
+    },
+    lost: "Oh non ! Vous n'avez plus de vies ! Voici quand même le mot :",
+    play_again_choices: "(o/n)",
+    yes_answer: "o",
+    no_answer: "n",
+    thanks_for_playing: "Merci d'avoir joué !",
+    session_summary: Plural {
+        one: "Vous avez joué {count} partie, gagné {won} et perdu {lost}.",
+        other: "Vous avez joué {count} parties, gagné {won} et perdu {lost}.",
+    },
+    unreadable_input: "\u{2717} Désolé, je n'ai pas pu lire cela. Veuillez réessayer.",
+    new_game_started: "Vous abandonnez ce mot. En voici un nouveau !",
+    setting_changed:
+        "\u{2713} « {name} » vaut maintenant {value}. Cela s'applique dès la prochaine partie.",
+    hint: "\u{2713} Un indice : « {letter} » est dans le mot. Cela vous a coûté une vie !",
+    hint_not_enough_lives: "\u{2717} Il vous faut plus d'une vie pour utiliser un indice !",
+    solve_correct: "\u{2713} Incroyable ! Le mot est bien « {word} » !",
+    solve_incorrect: "\u{2717} Désolé ! Le mot n'est pas « {word} » !",
+    solve_invalid:
+        "\u{2717} Les mots ne contiennent que les lettres de A à Z, ce ne peut donc pas être « {word} ».",
+    only_during_a_game: "\u{2717} Cette commande ne fonctionne que pendant une partie.",
+    commands: "Commandes",
+    guess_a_letter: "Proposer une lettre",
+    command_descriptions: [
+        "Révéler une lettre au prix d'une vie",
+        "Proposer le mot entier, en perdant une vie si c'est faux",
+        "Annuler votre dernier coup",
+        "Rejouer le dernier coup annulé",
+        "Afficher les parties gagnées et perdues pendant cette session",
+        "Abandonner ce mot et commencer une nouvelle partie",
+        "Afficher les réglages",
+        "Modifier un réglage dès la prochaine partie",
+        "Afficher cette aide",
+        "Quitter le jeu",
+    ],
+    history_tip: "Utilisez les flèches haut et bas pour retrouver une saisie précédente.",
+    settings_tip: "Modifiez-en un avec « /settings NOM VALEUR », par exemple « /settings lives 8 ».",
+    played: "Jouées",
+    won_count: "Gagnées",
+    lost_count: "Perdues",
+    unknown_command:
+        "Je ne connais pas la commande « {command} ». Tapez /help pour voir les commandes.",
+    command_usage: "Utilisation : {usage}",
+    command_takes_no_arguments: "{command} ne prend pas d'arguments.",
+    share_result: "Partagez votre résultat :",
+
+    new_game: "Nouvelle partie",
+    game_modes: "Modes de jeu",
+    statistics: "Statistiques",
+    settings: "Réglages",
+    help: "Aide",
+    quit: "Quitter",
+    classic: "Classique",
+    classic_description: "Jouez avec le nombre de vies choisi dans les réglages.",
+    sudden_death: "Mort subite",
+    sudden_death_description: "Une seule mauvaise proposition termine la partie.",
+    daily: "Mot du jour",
+    daily_description: "Tout le monde joue le même mot aujourd'hui, en un seul essai.",
+    active_game_mode: "{mode} (actif)",
+    game_mode: "Mode de jeu",
+    lives: "Vies",
+    minimum_word_length: "Longueur minimale des mots",
+    maximum_word_length: "Longueur maximale des mots",
+    exact_word_length: "Longueur exacte des mots",
+    unique_letters: "Lettres différentes",
+    reduced_motion: "Animations réduites",
+    accessible_mode: "Mode accessible",
+    undo_allowance: "Annulations par partie",
+    undo_cost: "Vies par annulation",
+    confirm_guesses: "Confirmer les propositions",
+    category: "Catégorie",
+    any_category: "Toutes",
+    difficulty: "Difficulté",
+    easy: "Facile",
+    medium: "Moyenne",
+    hard: "Difficile",
+    no_limit: "Sans limite",
+    exit: "Sortir",
+    back: "Retour",
+    menu: "Menu",
+    move_selection: "Déplacer",
+    select: "Choisir",
+    change: "Modifier",
+    make_guess: "Proposer",
+    select_letter: "Choisir",
+    confirm: "Valider",
+    letter_selected: "Appuyez sur {key} pour proposer « {letter} », ou tapez une autre lettre.",
+    undo: "Annuler",
+    redo: "Rétablir",
+    copy_result: "Copier le résultat",
+    result_copied: "Résultat copié dans le presse-papiers.",
+    close_help: "Fermer l'aide",
+    controls: "Commandes",
+    gallows: "Potence",
+    current_game: "Partie en cours",
+    remaining_letters: "Lettres restantes",
+    guesses: "Propositions",
+    header_statistics: "Jouées : {played}, gagnées : {won}, perdues : {lost}",
+    lives_remaining_label: Plural {
+        one: "vie restante",
+        other: "vies restantes",
+    },
+    you_won: "Gagné !",
+    you_lost: "Perdu...",
+    games_played: "Parties jouées : {count}",
+    games_won: "Parties gagnées : {count}",
+    games_lost: "Parties perdues : {count}",
+    win_rate: "Taux de victoire : {rate} %",
+    accessible_header: "Pendu. Parties jouées : {played}, gagnées : {won}, perdues : {lost}.",
+    last_guess: "Dernière proposition : {message}",
+    play_again_selected: "Voulez-vous rejouer ? « {choice} » est sélectionné.",
+    controls_sentence: "Commandes : {controls}.",
+    control_sentence: "{key} pour {action}",
+};
@@ -0,0 +1,36 @@
+//! The game engine behind Hangman, without any user interface.
+//!
+//! A [`Game`] holds a word, the guesses made so far and the lives remaining. Guesses are made one
+//! letter at a time with [`Game::make_guess`], and [`Game::status`] reports when the game has been
+//! won or lost.
+//!
+//! ```
+//! use hangman::{CompleteGameStatus, Game, GameStatus, MakeGuessSuccess};
+//!
+//! let mut game = Game::from_word("crate", 3).expect("the word should be valid");
+//!
+//! assert_eq!(game.make_guess("c"), Ok(MakeGuessSuccess::Correct(String::from("C"))));
+//! assert_eq!(game.blanked_out_letters().concat(), "C____");
+//!
+//! for letter in ["r", "a", "t", "e"] {
+//!     game.make_guess(letter).expect("each letter should be a new guess");
+//! }
+//!
+//! assert_eq!(game.status(), GameStatus::Complete(CompleteGameStatus::Won));
+//! ```
+//!
+//! Errors and [`GameEvent`] are non-exhaustive so that new variants can be added without breaking
+//! code that matches on them, and [`Rules`] is set up with [`Rules::new`] so that new options can
+//! be too.
+
+#![deny(missing_docs)]
+
+pub mod daily;
+pub mod game;
+pub mod words;
+
+pub use game::{
+    BuildGameError, CompleteGameStatus, Game, GameBuilder, GameEvent, GameObserver, GameStatus,
+    GuessStatus, HintError, HintResult, InvalidWordError, MakeGuessError, MakeGuessResult,
+    MakeGuessSuccess, Move, Rules, SolveResult, UndoError, UndoResult,
+};
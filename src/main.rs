@@ -1,19 +1,104 @@
+use crate::cli::Arguments;
+use crate::config::Config;
+use crate::daily_records::DailyRecords;
+use crate::i18n::{fill, Locale};
 #[cfg(feature = "ratatui_game_runner")]
 use crate::ratatui_game_runner::RatatuiGameRunner;
+use crate::settings::Settings;
 #[cfg(feature = "stdio_game_runner")]
 use crate::stdio_game_runner::StdIOGameRunner;
+use crate::word_history::WordHistory;
+use std::{env, io, process};
 
+mod accessibility;
+mod banner;
+mod cli;
+mod config;
+mod daily_records;
 mod feature_checks;
-mod game;
+mod i18n;
+mod messages;
 #[cfg(feature = "ratatui_game_runner")]
 mod ratatui_game_runner;
+mod rules;
+mod settings;
 #[cfg(feature = "stdio_game_runner")]
 mod stdio_game_runner;
+mod word_history;
+mod word_packs;
+mod words_command;
 
 fn main() {
+    let arguments =
+        Arguments::parse(env::args().skip(1)).unwrap_or_else(|error| exit_with_error(error));
+
+    let config = Config::load().unwrap_or_else(|error| exit_with_error(error));
+    let mut settings =
+        Settings::from_config(&config).unwrap_or_else(|error| exit_with_error(error));
+
+    settings.locale = arguments.locale.unwrap_or_else(Locale::from_env);
+
+    for (name, value) in &arguments.settings {
+        settings
+            .set(name, value)
+            .unwrap_or_else(|error| exit_with_error(error));
+    }
+
+    if let Some(command) = &arguments.words_command {
+        match words_command::run(command, &settings, &mut io::stdout()) {
+            Ok(true) => process::exit(0),
+            Ok(false) => process::exit(1),
+            Err(error) => exit_with_error(error),
+        }
+    }
+
+    let (words, _) = word_packs::load_words(settings.family_friendly)
+        .unwrap_or_else(|error| exit_with_error(error));
+
+    settings
+        .validate_words(&words)
+        .unwrap_or_else(|error| exit_with_error(error));
+
+    let daily_records = DailyRecords::load().unwrap_or_else(|error| exit_with_error(error));
+    let (mut word_history, history_error) = WordHistory::load();
+
+    if let Some(error) = history_error {
+        eprintln!(
+            "{}",
+            fill(
+                settings.locale.catalogue().history_not_loaded,
+                &[("error", &error)]
+            )
+        );
+    }
+
+    if arguments.forget_recent_words {
+        word_history
+            .clear()
+            .unwrap_or_else(|error| exit_with_error(error));
+    }
+
     #[cfg(feature = "stdio_game_runner")]
-    StdIOGameRunner::run(10, 4);
+    StdIOGameRunner::run(
+        settings,
+        words,
+        daily_records,
+        word_history,
+        arguments.daily,
+    );
 
     #[cfg(feature = "ratatui_game_runner")]
-    RatatuiGameRunner::run(10, 4);
+    RatatuiGameRunner::run(
+        &config,
+        settings,
+        words,
+        daily_records,
+        word_history,
+        arguments.daily,
+    );
+}
+
+fn exit_with_error(error: impl std::fmt::Display) -> ! {
+    eprintln!("{}", error);
+    process::exit(1);
 }
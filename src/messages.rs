@@ -0,0 +1,245 @@
+use crate::{
+    daily_records::DailyRecord,
+    i18n::{fill, Locale},
+};
+use hangman::{
+    words::{PickWordError, WordFilter},
+    CompleteGameStatus, Game, GuessStatus, MakeGuessError, MakeGuessResult, MakeGuessSuccess, Move,
+    UndoError, UndoResult,
+};
+
+const SHARE_GRID_WIDTH: usize = 10;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Tone {
+    Positive,
+    Negative,
+}
+
+pub(crate) fn make_guess_result(
+    locale: Locale,
+    guess: &str,
+    make_guess_result: &MakeGuessResult,
+) -> (Tone, String) {
+    let text = locale.catalogue();
+
+    match make_guess_result {
+        Ok(MakeGuessSuccess::Correct(guess)) => (
+            Tone::Positive,
+            fill(text.guess_correct, &[("guess", guess)]),
+        ),
+        Ok(MakeGuessSuccess::Incorrect(guess)) => (
+            Tone::Negative,
+            fill(text.guess_incorrect, &[("guess", guess)]),
+        ),
+        Err(MakeGuessError::Empty) => (Tone::Negative, String::from(text.guess_empty)),
+        Err(MakeGuessError::TooLong) => (Tone::Negative, String::from(text.guess_too_long)),
+        Err(MakeGuessError::Invalid) => (
+            Tone::Negative,
+            fill(text.guess_invalid, &[("guess", &guess)]),
+        ),
+        Err(MakeGuessError::AlreadyGuessed(guess)) => (
+            Tone::Negative,
+            fill(text.guess_already_guessed, &[("guess", guess)]),
+        ),
+        Err(MakeGuessError::GameComplete) => (Tone::Negative, String::from(text.game_already_over)),
+        Err(error) => (Tone::Negative, error.to_string()),
+    }
+}
+
+/// Why no word could be picked, and what to change so that one can be.
+pub(crate) fn pick_word_error(locale: Locale, error: &PickWordError) -> String {
+    let text = locale.catalogue();
+    let reason = match error {
+        PickWordError::NoWords => String::from(text.no_words_to_pick),
+        PickWordError::NoMatchingWords(filter) => fill(
+            text.no_matching_words,
+            &[("words", &describe_filter(locale, filter))],
+        ),
+        PickWordError::InvalidWeights(_) => return String::from(text.invalid_weights),
+        // Reasons added to the engine later are only described in English until translated.
+        error => error.to_string(),
+    };
+
+    fill(text.no_words, &[("error", &reason)])
+}
+
+/// Describes the words a filter matches, such as `words in "en" with at least 4 letters`.
+fn describe_filter(locale: Locale, filter: &WordFilter) -> String {
+    let text = locale.catalogue();
+    let mut parts = vec![String::from(text.filter_words)];
+
+    if let Some(language) = filter.required_language() {
+        parts.push(fill(text.filter_language, &[("language", &language)]));
+    }
+
+    if let Some(category) = filter.required_category() {
+        parts.push(fill(text.filter_category, &[("category", &category)]));
+    }
+
+    match filter.length_range() {
+        (minimum, Some(maximum)) if minimum == maximum => {
+            parts.push(locale.plural(&text.filter_length, maximum))
+        }
+        (minimum, Some(maximum)) => parts.push(fill(
+            text.filter_length_range,
+            &[("minimum", &minimum), ("maximum", &maximum)],
+        )),
+        (minimum, None) if minimum > 1 => {
+            parts.push(fill(text.filter_minimum_length, &[("minimum", &minimum)]))
+        }
+        (_, None) => {}
+    }
+
+    if let Some(unique_letters) = filter.required_unique_letters() {
+        parts.push(locale.plural(&text.filter_unique_letters, unique_letters));
+    }
+
+    if !filter.excluded_letters().is_empty() {
+        parts.push(fill(
+            text.filter_excluded_letters,
+            &[("letters", &filter.excluded_letters())],
+        ));
+    }
+
+    parts.join(" ")
+}
+
+pub(crate) fn undo_result(
+    locale: Locale,
+    undo_result: &UndoResult,
+    undos_remaining: usize,
+) -> (Tone, String) {
+    let text = locale.catalogue();
+
+    match undo_result {
+        Ok(undone_move) => (
+            Tone::Positive,
+            fill(
+                locale.select(&text.undo_done, undos_remaining),
+                &[
+                    ("move", &describe_move(locale, undone_move)),
+                    ("count", &undos_remaining),
+                ],
+            ),
+        ),
+        Err(error) => (Tone::Negative, undo_error(locale, error)),
+    }
+}
+
+pub(crate) fn redo_result(locale: Locale, redo_result: &UndoResult) -> (Tone, String) {
+    let text = locale.catalogue();
+
+    match redo_result {
+        Ok(redone_move) => (
+            Tone::Positive,
+            fill(
+                text.redo_done,
+                &[("move", &describe_move(locale, redone_move))],
+            ),
+        ),
+        Err(error) => (Tone::Negative, undo_error(locale, error)),
+    }
+}
+
+/// The clue the word pack gives for the word, if any, shown while the word is being guessed.
+pub(crate) fn clue(locale: Locale, game: &Game) -> Option<String> {
+    let clue = game.entry()?.clue.as_ref()?;
+
+    Some(fill(locale.catalogue().clue_label, &[("clue", clue)]))
+}
+
+/// Describes what the word means, with a line for the definition and one for the example, if the
+/// word came from a word pack with a definition. Words without one are described by their clue.
+pub(crate) fn definition(locale: Locale, game: &Game) -> Vec<String> {
+    let text = locale.catalogue();
+
+    let Some(entry) = game.entry() else {
+        return Vec::new();
+    };
+
+    let Some(definition) = &entry.definition else {
+        return entry
+            .clue
+            .iter()
+            .map(|clue| {
+                fill(
+                    text.clue_definition,
+                    &[("word", &game.letters().concat()), ("clue", clue)],
+                )
+            })
+            .collect();
+    };
+
+    let mut lines = vec![fill(
+        text.definition,
+        &[
+            ("word", &game.letters().concat()),
+            ("part_of_speech", &definition.part_of_speech),
+            ("definition", &definition.definition),
+        ],
+    )];
+
+    if let Some(example) = &definition.example {
+        lines.push(fill(
+            text.example,
+            &[("example", &fill(text.quoted, &[("text", example)]))],
+        ));
+    }
+
+    lines
+}
+
+/// A summary of the word of the day that doesn't give the word away: a line with the result and
+/// lives left, then a green or red square for each guess in the order they were made.
+pub(crate) fn daily_share(locale: Locale, record: &DailyRecord) -> Vec<String> {
+    let text = locale.catalogue();
+
+    let mut lines = vec![match record.status {
+        CompleteGameStatus::Won => fill(
+            locale.select(&text.daily_won, record.lives_remaining),
+            &[
+                ("date", &record.date),
+                ("count", &record.lives_remaining),
+                ("lives", &record.lives),
+            ],
+        ),
+        CompleteGameStatus::Lost => fill(text.daily_lost, &[("date", &record.date)]),
+    }];
+
+    lines.extend(record.guesses.chunks(SHARE_GRID_WIDTH).map(|guesses| {
+        guesses
+            .iter()
+            .map(|status| match status {
+                GuessStatus::Correct => '\u{1F7E9}',
+                GuessStatus::Incorrect => '\u{1F7E5}',
+            })
+            .collect::<String>()
+    }));
+
+    lines
+}
+
+fn describe_move(locale: Locale, made_move: &Move) -> String {
+    let text = locale.catalogue();
+    let letters = made_move.letters();
+
+    if letters.is_empty() {
+        String::from(text.solve_attempt_move)
+    } else {
+        fill(text.quoted, &[("text", &letters.join(", "))])
+    }
+}
+
+fn undo_error(locale: Locale, error: &UndoError) -> String {
+    let text = locale.catalogue();
+
+    String::from(match error {
+        UndoError::NothingToUndo => text.nothing_to_undo,
+        UndoError::NothingToRedo => text.nothing_to_redo,
+        UndoError::NoUndosLeft => text.no_undos_left,
+        UndoError::NotEnoughLives => text.undo_not_enough_lives,
+        UndoError::GameComplete => text.game_already_over,
+        error => return error.to_string(),
+    })
+}
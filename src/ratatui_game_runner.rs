@@ -1,17 +1,57 @@
+mod animation;
 mod app;
+mod clipboard;
 mod event_handler;
+mod key_bindings;
 mod tui;
 mod ui;
 mod update;
 
-use crate::ratatui_game_runner::{app::App, event_handler::EventHandler, tui::Tui, update::update};
+use crate::{
+    config::Config,
+    daily_records::DailyRecords,
+    messages,
+    ratatui_game_runner::{
+        app::{App, GameMode},
+        event_handler::EventHandler,
+        key_bindings::KeyBindings,
+        tui::Tui,
+        update::update,
+    },
+    settings::Settings,
+    word_history::WordHistory,
+};
+use hangman::words::WordList;
 use ratatui::{backend::CrosstermBackend, Terminal};
+use std::process;
 
 pub(crate) struct RatatuiGameRunner;
 
 impl RatatuiGameRunner {
-    pub(crate) fn run(lives: usize, minimum_word_size: usize) {
-        let mut app = App::new(lives, minimum_word_size);
+    pub(crate) fn run(
+        config: &Config,
+        settings: Settings,
+        words: WordList,
+        daily_records: DailyRecords,
+        word_history: WordHistory,
+        daily: bool,
+    ) {
+        let key_bindings = KeyBindings::from_config(config).unwrap_or_else(|error| {
+            eprintln!("{}", error);
+            process::exit(1);
+        });
+
+        let locale = settings.locale;
+        let mut app = App::new(settings, key_bindings, words, daily_records, word_history)
+            .unwrap_or_else(|error| {
+                eprintln!("{}", messages::pick_word_error(locale, &error));
+                process::exit(1);
+            });
+
+        if daily {
+            app.game_mode = GameMode::Daily;
+            app.start_new_game();
+        }
 
         let backend = CrosstermBackend::new(std::io::stderr());
         let terminal = Terminal::new(backend).expect("failed to create Terminal");
@@ -26,6 +66,11 @@ impl RatatuiGameRunner {
                 &mut app,
                 tui.event_handler.next().expect("failed to read next event"),
             );
+
+            if let Some(text) = app.clipboard.take() {
+                tui.copy_to_clipboard(&text)
+                    .expect("failed to copy to the clipboard");
+            }
         }
 
         tui.exit().expect("tui failed to exit");
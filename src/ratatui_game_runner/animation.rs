@@ -0,0 +1,94 @@
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum AnimationKind {
+    RevealLetters(Vec<usize>),
+    LifeLost,
+    Swing,
+    Celebrate,
+}
+
+impl AnimationKind {
+    fn duration(&self) -> u64 {
+        match self {
+            AnimationKind::RevealLetters(_) => 2,
+            AnimationKind::LifeLost => 4,
+            AnimationKind::Swing => 12,
+            AnimationKind::Celebrate => 12,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Animation {
+    kind: AnimationKind,
+    started_at: u64,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct Animations {
+    tick: u64,
+    running: Vec<Animation>,
+}
+
+impl Animations {
+    pub(crate) fn tick(&mut self) {
+        self.tick += 1;
+
+        let tick = self.tick;
+        self.running
+            .retain(|animation| tick - animation.started_at < animation.kind.duration());
+    }
+
+    pub(crate) fn start(&mut self, kind: AnimationKind) {
+        self.running.push(Animation {
+            kind,
+            started_at: self.tick,
+        });
+    }
+
+    pub(crate) fn skip(&mut self) {
+        self.running.clear();
+    }
+
+    pub(crate) fn find(
+        &self,
+        predicate: impl Fn(&AnimationKind) -> bool,
+    ) -> Option<(&AnimationKind, u64)> {
+        self.running
+            .iter()
+            .find(|animation| predicate(&animation.kind))
+            .map(|animation| (&animation.kind, self.tick - animation.started_at))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn an_animation_reports_its_frame_until_its_duration_has_elapsed() {
+        let mut animations = Animations::default();
+
+        animations.start(AnimationKind::LifeLost);
+
+        for expected_frame in 0..4 {
+            assert_eq!(
+                animations.find(|kind| *kind == AnimationKind::LifeLost),
+                Some((&AnimationKind::LifeLost, expected_frame))
+            );
+            animations.tick();
+        }
+
+        assert_eq!(animations.find(|_| true), None);
+    }
+
+    #[test]
+    fn skipping_stops_every_running_animation() {
+        let mut animations = Animations::default();
+
+        animations.start(AnimationKind::Swing);
+        animations.start(AnimationKind::RevealLetters(vec![0, 2]));
+        animations.skip();
+
+        assert_eq!(animations.find(|_| true), None);
+    }
+}
@@ -1,4 +1,130 @@
-use crate::game::{CompleteGameStatus, Game, GameStatus, MakeGuessResult};
+use crate::{
+    daily_records::{DailyRecord, DailyRecords},
+    i18n::{fill, Catalogue, Locale},
+    messages::{self, Tone},
+    ratatui_game_runner::{
+        animation::{AnimationKind, Animations},
+        key_bindings::KeyBindings,
+    },
+    settings::Settings,
+    word_history::WordHistory,
+};
+use hangman::{
+    daily::Date,
+    words::{Difficulty, PickWordError, WordList},
+    CompleteGameStatus, Game, GameStatus, MakeGuessResult, MakeGuessSuccess, UndoResult,
+};
+use ratatui::layout::Rect;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum MainMenuItem {
+    NewGame,
+    GameModes,
+    Statistics,
+    Settings,
+    Help,
+    Quit,
+}
+
+impl MainMenuItem {
+    pub(crate) const ALL: [MainMenuItem; 6] = [
+        MainMenuItem::NewGame,
+        MainMenuItem::GameModes,
+        MainMenuItem::Statistics,
+        MainMenuItem::Settings,
+        MainMenuItem::Help,
+        MainMenuItem::Quit,
+    ];
+
+    pub(crate) fn label(&self, text: &'static Catalogue) -> &'static str {
+        match self {
+            MainMenuItem::NewGame => text.new_game,
+            MainMenuItem::GameModes => text.game_modes,
+            MainMenuItem::Statistics => text.statistics,
+            MainMenuItem::Settings => text.settings,
+            MainMenuItem::Help => text.help,
+            MainMenuItem::Quit => text.quit,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum GameMode {
+    Classic,
+    SuddenDeath,
+    Daily,
+}
+
+impl GameMode {
+    pub(crate) const ALL: [GameMode; 3] =
+        [GameMode::Classic, GameMode::SuddenDeath, GameMode::Daily];
+
+    pub(crate) fn label(&self, text: &'static Catalogue) -> &'static str {
+        match self {
+            GameMode::Classic => text.classic,
+            GameMode::SuddenDeath => text.sudden_death,
+            GameMode::Daily => text.daily,
+        }
+    }
+
+    pub(crate) fn description(&self, text: &'static Catalogue) -> &'static str {
+        match self {
+            GameMode::Classic => text.classic_description,
+            GameMode::SuddenDeath => text.sudden_death_description,
+            GameMode::Daily => text.daily_description,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum SettingsItem {
+    Lives,
+    MinimumWordSize,
+    MaximumWordSize,
+    ExactWordSize,
+    UniqueLetters,
+    ReducedMotion,
+    AccessibleMode,
+    UndoAllowance,
+    UndoCost,
+    ConfirmGuesses,
+    Category,
+    Difficulty,
+}
+
+impl SettingsItem {
+    pub(crate) const ALL: [SettingsItem; 12] = [
+        SettingsItem::Lives,
+        SettingsItem::MinimumWordSize,
+        SettingsItem::MaximumWordSize,
+        SettingsItem::ExactWordSize,
+        SettingsItem::UniqueLetters,
+        SettingsItem::Category,
+        SettingsItem::Difficulty,
+        SettingsItem::UndoAllowance,
+        SettingsItem::UndoCost,
+        SettingsItem::ConfirmGuesses,
+        SettingsItem::ReducedMotion,
+        SettingsItem::AccessibleMode,
+    ];
+
+    pub(crate) fn label(&self, text: &'static Catalogue) -> &'static str {
+        match self {
+            SettingsItem::Lives => text.lives,
+            SettingsItem::MinimumWordSize => text.minimum_word_length,
+            SettingsItem::MaximumWordSize => text.maximum_word_length,
+            SettingsItem::ExactWordSize => text.exact_word_length,
+            SettingsItem::UniqueLetters => text.unique_letters,
+            SettingsItem::ReducedMotion => text.reduced_motion,
+            SettingsItem::AccessibleMode => text.accessible_mode,
+            SettingsItem::UndoAllowance => text.undo_allowance,
+            SettingsItem::UndoCost => text.undo_cost,
+            SettingsItem::ConfirmGuesses => text.confirm_guesses,
+            SettingsItem::Category => text.category,
+            SettingsItem::Difficulty => text.difficulty,
+        }
+    }
+}
 
 #[derive(Eq, PartialEq)]
 pub(crate) enum PlayAgain {
@@ -6,6 +132,51 @@ pub(crate) enum PlayAgain {
     No,
 }
 
+pub(crate) enum Feedback {
+    Guess {
+        guess: String,
+        result: MakeGuessResult,
+    },
+    Undo {
+        result: UndoResult,
+        undos_remaining: usize,
+    },
+    Redo(UndoResult),
+}
+
+impl Feedback {
+    pub(crate) fn message(&self, locale: Locale) -> (Tone, String) {
+        match self {
+            Feedback::Guess { guess, result } => messages::make_guess_result(locale, guess, result),
+            Feedback::Undo {
+                result,
+                undos_remaining,
+            } => messages::undo_result(locale, result, *undos_remaining),
+            Feedback::Redo(result) => messages::redo_result(locale, result),
+        }
+    }
+}
+
+pub(crate) struct LastMove {
+    pub(crate) feedback: Feedback,
+    pub(crate) ticks_remaining: u16,
+}
+
+#[derive(Eq, PartialEq)]
+pub(crate) struct MainMenuState {
+    pub(crate) currently_selected: MainMenuItem,
+}
+
+#[derive(Eq, PartialEq)]
+pub(crate) struct GameModesState {
+    pub(crate) currently_selected: GameMode,
+}
+
+#[derive(Eq, PartialEq)]
+pub(crate) struct SettingsState {
+    pub(crate) currently_selected: SettingsItem,
+}
+
 #[derive(Eq, PartialEq)]
 pub(crate) struct GameCompleteState {
     pub(crate) currently_selected: PlayAgain,
@@ -13,50 +184,435 @@ pub(crate) struct GameCompleteState {
 
 #[derive(Eq, PartialEq)]
 pub(crate) enum CurrentView {
+    MainMenu(MainMenuState),
+    GameModes(GameModesState),
+    Statistics,
+    Settings(SettingsState),
     GameInProgress,
     GameComplete(GameCompleteState),
+    DailyPlayed,
 }
 
 pub(crate) struct App {
     pub(crate) current_view: CurrentView,
-    pub(crate) lives: usize,
-    pub(crate) minimum_word_size: usize,
+    pub(crate) settings: Settings,
+    pub(crate) words: WordList,
+    pub(crate) game_mode: GameMode,
+    pub(crate) key_bindings: KeyBindings,
     pub(crate) current_game: Game,
-    pub(crate) last_guess_result: Option<MakeGuessResult>,
+    /// Whether the current game is the word of the day.
+    pub(crate) is_daily_game: bool,
+    pub(crate) today: Date,
+    pub(crate) daily_records: DailyRecords,
+    pub(crate) word_history: WordHistory,
+    /// Text waiting to be copied to the clipboard by the terminal.
+    pub(crate) clipboard: Option<String>,
+    /// A message about the word of the day, such as the result having been copied.
+    pub(crate) daily_notice: Option<String>,
+    /// Why the last game couldn't start, shown on the main menu.
+    pub(crate) word_notice: Option<String>,
+    pub(crate) last_move: Option<LastMove>,
+    pub(crate) selected_letter: Option<String>,
     pub(crate) games_played: u16,
     pub(crate) games_won: u16,
     pub(crate) games_lost: u16,
+    pub(crate) animations: Animations,
+    pub(crate) menu_item_areas: Vec<Rect>,
+    pub(crate) show_help: bool,
     pub(crate) should_quit: bool,
 }
 
 impl App {
-    pub(crate) fn new(lives: usize, minimum_word_size: usize) -> Self {
-        App {
-            current_view: CurrentView::GameInProgress,
-            lives,
-            minimum_word_size,
-            current_game: Game::new(lives, minimum_word_size),
-            last_guess_result: None,
+    pub(crate) const MAXIMUM_LIVES: usize = 26;
+    pub(crate) const MAXIMUM_MINIMUM_WORD_SIZE: usize = 12;
+    pub(crate) const MAXIMUM_MAXIMUM_WORD_SIZE: usize = 20;
+    pub(crate) const MAXIMUM_UNIQUE_LETTERS: usize = 12;
+    pub(crate) const MAXIMUM_UNDO_ALLOWANCE: usize = 10;
+    pub(crate) const MAXIMUM_UNDO_COST: usize = 5;
+    pub(crate) const LAST_MOVE_DISPLAY_TICKS: u16 = 12;
+    pub(crate) const LAST_MOVE_FADE_TICKS: u16 = 4;
+
+    pub(crate) fn new(
+        settings: Settings,
+        key_bindings: KeyBindings,
+        words: WordList,
+        daily_records: DailyRecords,
+        word_history: WordHistory,
+    ) -> Result<Self, PickWordError> {
+        Ok(App {
+            current_view: CurrentView::MainMenu(MainMenuState {
+                currently_selected: MainMenuItem::NewGame,
+            }),
+            current_game: settings.new_game(&words, word_history.recent(), settings.lives)?,
+            is_daily_game: false,
+            today: Date::today(),
+            daily_records,
+            word_history,
+            clipboard: None,
+            daily_notice: None,
+            word_notice: None,
+            settings,
+            words,
+            game_mode: GameMode::Classic,
+            key_bindings,
+            last_move: None,
+            selected_letter: None,
             games_played: 0,
             games_won: 0,
             games_lost: 0,
+            animations: Animations::default(),
+            menu_item_areas: Vec::new(),
+            show_help: false,
             should_quit: false,
+        })
+    }
+
+    pub(crate) fn tick(&mut self) {
+        self.animations.tick();
+
+        if let Some(last_move) = &mut self.last_move {
+            last_move.ticks_remaining = last_move.ticks_remaining.saturating_sub(1);
+
+            if last_move.ticks_remaining == 0 {
+                self.last_move = None;
+            }
         }
     }
 
     pub(crate) fn start_new_game(&mut self) {
-        self.current_game = Game::new(self.lives, self.minimum_word_size);
+        if self.game_mode == GameMode::Daily {
+            self.start_daily_game();
+            return;
+        }
+
+        self.leave_daily_game();
+
+        match self.settings.new_game(
+            &self.words,
+            self.word_history.recent(),
+            self.game_mode_lives(),
+        ) {
+            Ok(game) => {
+                self.current_game = game;
+                // Failing to save only means the word may come up again sooner.
+                let _ = self.word_history.record(&self.current_game);
+                self.show_new_game();
+            }
+            Err(error) => self.show_word_notice(error),
+        }
+    }
+
+    /// Starts the word of the day, carries on with it if it is already in progress, or shows how
+    /// it went if it has already been played.
+    fn start_daily_game(&mut self) {
+        self.daily_notice = None;
+
+        if self.daily_records.get(self.today).is_some() {
+            self.current_view = CurrentView::DailyPlayed;
+            return;
+        }
+
+        if self.is_daily_game && self.current_game.status() == GameStatus::InProgress {
+            self.animations.skip();
+            self.current_view = CurrentView::GameInProgress;
+            return;
+        }
+
+        match self.settings.daily_game(self.today) {
+            Ok(game) => {
+                self.current_game = game;
+                self.is_daily_game = true;
+                self.show_new_game();
+            }
+            Err(error) => self.show_word_notice(error),
+        }
+    }
+
+    fn show_new_game(&mut self) {
+        self.word_notice = None;
+        self.last_move = None;
+        self.selected_letter = None;
+        self.animations.skip();
         self.current_view = CurrentView::GameInProgress;
     }
 
+    /// Goes back to the main menu with why no word could be picked and what to change.
+    fn show_word_notice(&mut self, error: PickWordError) {
+        self.word_notice = Some(messages::pick_word_error(self.settings.locale, &error));
+        self.show_main_menu();
+    }
+
+    /// Records the word of the day as lost if it is left before it ends, so it can't be tried
+    /// again.
+    fn leave_daily_game(&mut self) {
+        if self.is_daily_game && self.current_game.status() == GameStatus::InProgress {
+            self.record_daily_game();
+        }
+
+        self.is_daily_game = false;
+    }
+
+    fn record_daily_game(&mut self) {
+        let record = DailyRecord::from_game(self.today, &self.current_game, self.settings.lives);
+
+        self.daily_notice = self.daily_records.record(record).err().map(|error| {
+            fill(
+                self.settings.locale.catalogue().daily_not_saved,
+                &[("error", &error)],
+            )
+        });
+    }
+
+    /// Asks the terminal to copy the result of today's word, if it has been played.
+    pub(crate) fn copy_daily_result(&mut self) {
+        let Some(record) = self.daily_records.get(self.today) else {
+            return;
+        };
+
+        self.clipboard = Some(messages::daily_share(self.settings.locale, record).join("\n"));
+        self.daily_notice = Some(String::from(self.settings.locale.catalogue().result_copied));
+    }
+
+    pub(crate) fn show_main_menu(&mut self) {
+        self.current_view = CurrentView::MainMenu(MainMenuState {
+            currently_selected: MainMenuItem::NewGame,
+        });
+    }
+
+    pub(crate) fn select_main_menu_item(&mut self, item: MainMenuItem) {
+        match item {
+            MainMenuItem::NewGame => self.start_new_game(),
+            MainMenuItem::GameModes => {
+                self.current_view = CurrentView::GameModes(GameModesState {
+                    currently_selected: self.game_mode,
+                })
+            }
+            MainMenuItem::Statistics => self.current_view = CurrentView::Statistics,
+            MainMenuItem::Settings => {
+                self.current_view = CurrentView::Settings(SettingsState {
+                    currently_selected: SettingsItem::Lives,
+                })
+            }
+            MainMenuItem::Help => self.toggle_help(),
+            MainMenuItem::Quit => self.quit(),
+        }
+    }
+
+    pub(crate) fn toggle_help(&mut self) {
+        self.show_help = !self.show_help;
+    }
+
+    pub(crate) fn game_mode_lives(&self) -> usize {
+        match self.game_mode {
+            GameMode::Classic | GameMode::Daily => self.settings.lives,
+            GameMode::SuddenDeath => 1,
+        }
+    }
+
+    pub(crate) fn select_game_mode(&mut self, game_mode: GameMode) {
+        self.game_mode = game_mode;
+        self.show_main_menu();
+    }
+
+    pub(crate) fn adjust_setting(&mut self, item: SettingsItem, increase: bool) {
+        let (value, minimum, maximum) = match item {
+            SettingsItem::Lives => (&mut self.settings.lives, 1, Self::MAXIMUM_LIVES),
+            SettingsItem::MinimumWordSize => (
+                &mut self.settings.minimum_word_size,
+                1,
+                // The minimum can't pass the maximum set in the config file.
+                self.settings
+                    .maximum_word_size
+                    .map_or(Self::MAXIMUM_MINIMUM_WORD_SIZE, |maximum| {
+                        maximum.min(Self::MAXIMUM_MINIMUM_WORD_SIZE)
+                    }),
+            ),
+            SettingsItem::MaximumWordSize => {
+                self.adjust_maximum_word_size(increase);
+                return;
+            }
+            SettingsItem::ExactWordSize => {
+                self.adjust_exact_word_size(increase);
+                return;
+            }
+            SettingsItem::UniqueLetters => {
+                // Any number of different letters comes before 1.
+                self.settings.unique_letters = match (self.settings.unique_letters, increase) {
+                    (None, true) => Some(1),
+                    (Some(unique_letters), true)
+                        if unique_letters < Self::MAXIMUM_UNIQUE_LETTERS =>
+                    {
+                        Some(unique_letters + 1)
+                    }
+                    (Some(1), false) | (None, false) => None,
+                    (Some(unique_letters), false) => Some(unique_letters - 1),
+                    (unique_letters, true) => unique_letters,
+                };
+                return;
+            }
+            SettingsItem::UndoAllowance => (
+                &mut self.settings.undo_allowance,
+                0,
+                Self::MAXIMUM_UNDO_ALLOWANCE,
+            ),
+            SettingsItem::UndoCost => (&mut self.settings.undo_cost, 0, Self::MAXIMUM_UNDO_COST),
+            SettingsItem::ReducedMotion => {
+                self.settings.reduced_motion = !self.settings.reduced_motion;
+                self.animations.skip();
+                return;
+            }
+            SettingsItem::AccessibleMode => {
+                self.settings.accessible = !self.settings.accessible;
+                return;
+            }
+            SettingsItem::ConfirmGuesses => {
+                self.settings.confirm_guesses = !self.settings.confirm_guesses;
+                return;
+            }
+            SettingsItem::Category => {
+                self.cycle_category(increase);
+                return;
+            }
+            SettingsItem::Difficulty => {
+                let index = Difficulty::ALL
+                    .iter()
+                    .position(|difficulty| *difficulty == self.settings.difficulty)
+                    .unwrap_or_default();
+                let index = if increase {
+                    (index + 1).min(Difficulty::ALL.len() - 1)
+                } else {
+                    index.saturating_sub(1)
+                };
+
+                self.settings.difficulty = Difficulty::ALL[index];
+                return;
+            }
+        };
+
+        // Values from the config file can be out of the menu's range and only move towards it.
+        if increase && *value < maximum {
+            *value += 1;
+        } else if !increase && *value > minimum {
+            *value -= 1;
+        }
+    }
+
+    /// The length words must be, if the minimum and maximum word sizes are the same.
+    pub(crate) fn exact_word_size(&self) -> Option<usize> {
+        self.settings
+            .maximum_word_size
+            .filter(|maximum| *maximum == self.settings.minimum_word_size)
+    }
+
+    /// Raises or lowers the longest word that can be picked, where any length comes after the
+    /// largest one. The maximum can't go below the minimum word size.
+    fn adjust_maximum_word_size(&mut self, increase: bool) {
+        let minimum = self.settings.minimum_word_size;
+
+        self.settings.maximum_word_size = match (self.settings.maximum_word_size, increase) {
+            (Some(maximum), true) if maximum >= Self::MAXIMUM_MAXIMUM_WORD_SIZE => None,
+            (Some(maximum), true) => Some(maximum + 1),
+            (Some(maximum), false) if maximum > minimum => Some(maximum - 1),
+            (None, false) => Some(Self::MAXIMUM_MAXIMUM_WORD_SIZE.max(minimum)),
+            (maximum, _) => maximum,
+        };
+    }
+
+    /// Sets the minimum and maximum word sizes to the same length, starting from the minimum,
+    /// where any length comes before 1. Going back to any length only removes the maximum.
+    fn adjust_exact_word_size(&mut self, increase: bool) {
+        let exact_word_size = match (self.exact_word_size(), increase) {
+            (None, true) => Some(self.settings.minimum_word_size),
+            (Some(size), true) if size < Self::MAXIMUM_MINIMUM_WORD_SIZE => Some(size + 1),
+            (Some(1), false) | (None, false) => None,
+            (Some(size), false) => Some(size - 1),
+            (size, true) => size,
+        };
+
+        if let Some(size) = exact_word_size {
+            self.settings.minimum_word_size = size;
+        }
+
+        self.settings.maximum_word_size = exact_word_size;
+    }
+
+    /// Moves to the next or previous category, where any category comes before the first one.
+    fn cycle_category(&mut self, forwards: bool) {
+        let categories = self.settings.categories(&self.words);
+        let choices = categories.len() + 1;
+        let index = self.settings.category.as_ref().map_or(0, |category| {
+            categories
+                .iter()
+                .position(|choice| choice == category)
+                .map_or(0, |position| position + 1)
+        });
+        let index = if forwards {
+            (index + 1) % choices
+        } else {
+            (index + choices - 1) % choices
+        };
+
+        self.settings.category = index
+            .checked_sub(1)
+            .map(|position| categories[position].clone());
+    }
+
+    /// Selects a letter to be guessed once it is confirmed. Anything other than a letter is
+    /// guessed straight away, since an invalid guess never costs a life.
+    pub(crate) fn select_letter(&mut self, char: char) {
+        if char.is_ascii_alphabetic() {
+            self.selected_letter = Some(char.to_ascii_uppercase().to_string());
+        } else {
+            self.make_guess(&char.to_string());
+        }
+    }
+
+    pub(crate) fn confirm_guess(&mut self) {
+        if let Some(letter) = self.selected_letter.take() {
+            self.make_guess(&letter);
+        }
+    }
+
     pub(crate) fn make_guess(&mut self, guess: &str) {
         if let GameStatus::Complete(_) = self.current_game.status() {
             return;
         };
 
-        self.last_guess_result = Some(self.current_game.make_guess(guess));
+        self.selected_letter = None;
+
+        let result = self.current_game.make_guess(guess);
 
+        match &result {
+            Ok(MakeGuessSuccess::Correct(letter)) => {
+                let positions = self
+                    .current_game
+                    .letters()
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, x)| *x == letter)
+                    .map(|(index, _)| index)
+                    .collect();
+
+                self.start_animation(AnimationKind::RevealLetters(positions));
+            }
+            Ok(MakeGuessSuccess::Incorrect(_)) => self.start_animation(AnimationKind::LifeLost),
+            Err(_) => {}
+        }
+
+        self.show_feedback(Feedback::Guess {
+            guess: guess.to_string(),
+            result,
+        });
+
+        self.complete_game_if_over();
+    }
+
+    fn complete_game_if_over(&mut self) {
         if let GameStatus::Complete(complete_game_status) = self.current_game.status() {
+            if self.is_daily_game {
+                self.record_daily_game();
+            }
+
             self.games_played += 1;
             self.current_view = CurrentView::GameComplete(GameCompleteState {
                 currently_selected: PlayAgain::Yes,
@@ -64,15 +620,438 @@ impl App {
             match complete_game_status {
                 CompleteGameStatus::Won => {
                     self.games_won += 1;
+                    self.start_animation(AnimationKind::Celebrate);
                 }
                 CompleteGameStatus::Lost => {
                     self.games_lost += 1;
+                    self.start_animation(AnimationKind::Swing);
                 }
             };
         };
     }
 
+    pub(crate) fn undo(&mut self) {
+        let result = self.current_game.undo();
+
+        if result.is_ok() {
+            self.animations.skip();
+        }
+
+        self.show_feedback(Feedback::Undo {
+            result,
+            undos_remaining: self.current_game.undos_remaining(),
+        });
+    }
+
+    pub(crate) fn redo(&mut self) {
+        let result = self.current_game.redo();
+
+        if let Ok(redone_move) = &result {
+            if redone_move.lives_lost > 0 {
+                self.start_animation(AnimationKind::LifeLost);
+            }
+        }
+
+        self.show_feedback(Feedback::Redo(result));
+        self.complete_game_if_over();
+    }
+
+    fn show_feedback(&mut self, feedback: Feedback) {
+        self.last_move = Some(LastMove {
+            feedback,
+            ticks_remaining: Self::LAST_MOVE_DISPLAY_TICKS,
+        });
+    }
+
+    fn start_animation(&mut self, kind: AnimationKind) {
+        if !self.settings.reduced_motion {
+            self.animations.start(kind);
+        }
+    }
+
     pub(crate) fn quit(&mut self) {
+        self.leave_daily_game();
         self.should_quit = true;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hangman::{
+        words::{ScreenKind, WordScreen},
+        MakeGuessError,
+    };
+
+    #[test]
+    fn the_last_move_is_kept_until_its_display_ticks_have_elapsed() {
+        let mut app = App::new(
+            Settings::default(),
+            KeyBindings::default(),
+            WordList::built_in().clone(),
+            DailyRecords::default(),
+            WordHistory::default(),
+        )
+        .unwrap();
+        app.start_new_game();
+
+        app.make_guess("1");
+
+        assert!(matches!(
+            app.last_move.as_ref().map(|last_move| &last_move.feedback),
+            Some(Feedback::Guess {
+                result: Err(MakeGuessError::Invalid),
+                ..
+            })
+        ));
+
+        for _ in 1..App::LAST_MOVE_DISPLAY_TICKS {
+            app.tick();
+        }
+        assert!(app.last_move.is_some());
+
+        app.tick();
+        assert!(app.last_move.is_none());
+    }
+
+    #[test]
+    fn changing_the_category_cycles_through_the_categories_and_applies_to_new_games() {
+        let words = WordList::parse(
+            "@language en\n@category animals\nhorse\n@category food\ncheese\n\
+             @language fr\n@category animaux\ncheval\n",
+        )
+        .unwrap();
+        let mut app = App::new(
+            Settings::default(),
+            KeyBindings::default(),
+            words,
+            DailyRecords::default(),
+            WordHistory::default(),
+        )
+        .unwrap();
+
+        app.adjust_setting(SettingsItem::Category, true);
+        assert_eq!(app.settings.category.as_deref(), Some("animals"));
+
+        app.adjust_setting(SettingsItem::Category, false);
+        app.adjust_setting(SettingsItem::Category, false);
+        assert_eq!(app.settings.category.as_deref(), Some("food"));
+
+        app.start_new_game();
+        assert_eq!(
+            app.current_game
+                .entry()
+                .map(|entry| entry.category.as_str()),
+            Some("food")
+        );
+
+        app.adjust_setting(SettingsItem::Category, true);
+        assert_eq!(app.settings.category, None);
+    }
+
+    #[test]
+    fn increasing_a_setting_above_the_menus_maximum_leaves_it_unchanged() {
+        let mut app = App::new(
+            Settings {
+                lives: 50,
+                ..Settings::default()
+            },
+            KeyBindings::default(),
+            WordList::built_in().clone(),
+            DailyRecords::default(),
+            WordHistory::default(),
+        )
+        .unwrap();
+
+        app.adjust_setting(SettingsItem::Lives, true);
+        assert_eq!(app.settings.lives, 50);
+
+        app.adjust_setting(SettingsItem::Lives, false);
+        assert_eq!(app.settings.lives, 49);
+
+        app.settings.lives = App::MAXIMUM_LIVES;
+        app.adjust_setting(SettingsItem::Lives, true);
+        assert_eq!(app.settings.lives, App::MAXIMUM_LIVES);
+    }
+
+    #[test]
+    fn the_maximum_word_size_stays_above_the_minimum_and_goes_to_any_past_the_largest() {
+        let mut app = App::new(
+            Settings::default(),
+            KeyBindings::default(),
+            WordList::built_in().clone(),
+            DailyRecords::default(),
+            WordHistory::default(),
+        )
+        .unwrap();
+
+        app.adjust_setting(SettingsItem::MaximumWordSize, false);
+        assert_eq!(
+            app.settings.maximum_word_size,
+            Some(App::MAXIMUM_MAXIMUM_WORD_SIZE)
+        );
+
+        app.adjust_setting(SettingsItem::MaximumWordSize, true);
+        assert_eq!(app.settings.maximum_word_size, None);
+
+        app.settings.maximum_word_size = Some(5);
+        for _ in 0..3 {
+            app.adjust_setting(SettingsItem::MaximumWordSize, false);
+        }
+        assert_eq!(app.settings.maximum_word_size, Some(4));
+
+        app.adjust_setting(SettingsItem::MinimumWordSize, true);
+        assert_eq!(app.settings.minimum_word_size, 4);
+    }
+
+    #[test]
+    fn the_exact_word_size_sets_the_minimum_and_maximum_together() {
+        let mut app = App::new(
+            Settings::default(),
+            KeyBindings::default(),
+            WordList::built_in().clone(),
+            DailyRecords::default(),
+            WordHistory::default(),
+        )
+        .unwrap();
+
+        assert_eq!(app.exact_word_size(), None);
+
+        app.adjust_setting(SettingsItem::ExactWordSize, true);
+        app.adjust_setting(SettingsItem::ExactWordSize, true);
+        assert_eq!(app.exact_word_size(), Some(5));
+        assert_eq!(
+            (
+                app.settings.minimum_word_size,
+                app.settings.maximum_word_size
+            ),
+            (5, Some(5))
+        );
+        assert_eq!(app.settings.validate_words(&app.words), Ok(()));
+
+        app.settings.minimum_word_size = 1;
+        app.settings.maximum_word_size = Some(1);
+        app.adjust_setting(SettingsItem::ExactWordSize, false);
+        assert_eq!(app.exact_word_size(), None);
+        assert_eq!(
+            (
+                app.settings.minimum_word_size,
+                app.settings.maximum_word_size
+            ),
+            (1, None)
+        );
+    }
+
+    #[test]
+    fn the_number_of_different_letters_goes_from_any_to_the_largest() {
+        let mut app = App::new(
+            Settings::default(),
+            KeyBindings::default(),
+            WordList::built_in().clone(),
+            DailyRecords::default(),
+            WordHistory::default(),
+        )
+        .unwrap();
+
+        app.adjust_setting(SettingsItem::UniqueLetters, false);
+        assert_eq!(app.settings.unique_letters, None);
+
+        app.adjust_setting(SettingsItem::UniqueLetters, true);
+        app.adjust_setting(SettingsItem::UniqueLetters, true);
+        assert_eq!(app.settings.unique_letters, Some(2));
+
+        app.settings.unique_letters = Some(App::MAXIMUM_UNIQUE_LETTERS);
+        app.adjust_setting(SettingsItem::UniqueLetters, true);
+        assert_eq!(
+            app.settings.unique_letters,
+            Some(App::MAXIMUM_UNIQUE_LETTERS)
+        );
+
+        app.settings.unique_letters = Some(1);
+        app.adjust_setting(SettingsItem::UniqueLetters, false);
+        assert_eq!(app.settings.unique_letters, None);
+    }
+
+    #[test]
+    fn the_difficulty_goes_from_easy_to_hard_and_weights_that_cant_be_used_say_so() {
+        let mut app = App::new(
+            Settings::default(),
+            KeyBindings::default(),
+            WordList::built_in().clone(),
+            DailyRecords::default(),
+            WordHistory::default(),
+        )
+        .unwrap();
+
+        app.adjust_setting(SettingsItem::Difficulty, true);
+        app.adjust_setting(SettingsItem::Difficulty, true);
+        assert_eq!(app.settings.difficulty, Difficulty::Hard);
+
+        for _ in 0..3 {
+            app.adjust_setting(SettingsItem::Difficulty, false);
+        }
+        assert_eq!(app.settings.difficulty, Difficulty::Easy);
+
+        app.show_word_notice(PickWordError::InvalidWeights(Difficulty::Easy));
+        assert_eq!(
+            app.word_notice.as_deref(),
+            Some(
+                "No word could be picked because the word frequencies are too large or small to \
+                 weigh the words by. Try another difficulty or check the frequencies in your word \
+                 packs."
+            )
+        );
+    }
+
+    #[test]
+    fn when_no_word_matches_the_settings_the_main_menu_says_what_to_change() {
+        let words = WordList::parse("@language en\n@category animals\nhorse\n").unwrap();
+        let mut app = App::new(
+            Settings::default(),
+            KeyBindings::default(),
+            words,
+            DailyRecords::default(),
+            WordHistory::default(),
+        )
+        .unwrap();
+
+        app.settings.minimum_word_size = 6;
+        app.start_new_game();
+
+        assert!(matches!(app.current_view, CurrentView::MainMenu(_)));
+        assert_eq!(
+            app.word_notice.as_deref(),
+            Some(
+                "No word could be picked: there are no words in \"en\" with at least 6 letters. \
+                 Try a smaller minimum word size, a larger maximum, another category or more word \
+                 packs."
+            )
+        );
+
+        app.settings.minimum_word_size = 5;
+        app.start_new_game();
+
+        assert!(app.current_view == CurrentView::GameInProgress);
+        assert_eq!(app.word_notice, None);
+        assert!(App::new(
+            Settings::default(),
+            KeyBindings::default(),
+            WordList::default(),
+            DailyRecords::default(),
+            WordHistory::default(),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn the_word_of_the_day_can_only_be_tried_once_and_its_result_copied() {
+        let words = WordList::parse("@language en\n@category animals\nhorse\n").unwrap();
+        let mut app = App::new(
+            Settings::default(),
+            KeyBindings::default(),
+            words,
+            DailyRecords::default(),
+            WordHistory::default(),
+        )
+        .unwrap();
+
+        app.select_game_mode(GameMode::Daily);
+        app.start_new_game();
+        app.make_guess("z");
+        app.show_main_menu();
+        app.start_new_game();
+        assert_eq!(app.current_game.guess_letters(), [String::from("Z")]);
+
+        app.select_game_mode(GameMode::Classic);
+        app.start_new_game();
+        app.select_game_mode(GameMode::Daily);
+        app.start_new_game();
+        assert!(app.current_view == CurrentView::DailyPlayed);
+        assert_eq!(
+            app.daily_records
+                .get(app.today)
+                .map(|record| record.status.clone()),
+            Some(CompleteGameStatus::Lost)
+        );
+
+        app.copy_daily_result();
+        assert_eq!(
+            app.clipboard,
+            Some(format!("Hangman {}: not solved\n\u{1F7E5}", app.today))
+        );
+    }
+
+    #[test]
+    fn local_word_packs_and_blocklists_leave_the_word_of_the_day_unchanged() {
+        let settings = Settings::default();
+        let daily_word = settings
+            .daily_game(Date::today())
+            .unwrap()
+            .letters()
+            .concat();
+        let mut words = WordList::built_in().clone();
+        words.extend(
+            WordList::parse("@language en\n@category team\naardvark\nbadger\nnarwhal\n").unwrap(),
+        );
+        words.screen(&[WordScreen::parse("team.block", ScreenKind::Block, &daily_word).unwrap()]);
+        let mut app = App::new(
+            settings,
+            KeyBindings::default(),
+            words,
+            DailyRecords::default(),
+            WordHistory::default(),
+        )
+        .unwrap();
+
+        app.select_game_mode(GameMode::Daily);
+        app.start_new_game();
+
+        assert!(app
+            .words
+            .entries()
+            .iter()
+            .all(|entry| entry.word != daily_word));
+        assert_eq!(app.current_game.letters().concat(), daily_word);
+    }
+
+    #[test]
+    fn undoing_a_wrong_guess_restores_the_life_and_shows_feedback() {
+        let mut app = App::new(
+            Settings::default(),
+            KeyBindings::default(),
+            WordList::built_in().clone(),
+            DailyRecords::default(),
+            WordHistory::default(),
+        )
+        .unwrap();
+        app.start_new_game();
+
+        let letters = app.current_game.letters();
+        let wrong_letter = ('A'..='Z')
+            .map(String::from)
+            .find(|letter| !letters.contains(letter))
+            .expect("no word uses every letter");
+
+        app.make_guess(&wrong_letter);
+        app.undo();
+
+        assert_eq!(
+            app.current_game.lives_remaining(),
+            Settings::default().lives
+        );
+        assert!(matches!(
+            app.last_move.as_ref().map(|last_move| &last_move.feedback),
+            Some(Feedback::Undo {
+                result: Ok(_),
+                undos_remaining: 2
+            })
+        ));
+
+        app.redo();
+
+        assert_eq!(
+            app.current_game.guess_letters(),
+            vec![wrong_letter.to_uppercase()]
+        );
+    }
+}
@@ -11,13 +11,16 @@ pub(crate) enum Event {
     Tick,
     Key(KeyEvent),
     Mouse(MouseEvent),
+    #[allow(dead_code)]
     Resize(u16, u16),
 }
 
 #[derive(Debug)]
 pub(crate) struct EventHandler {
+    #[allow(dead_code)]
     sender: mpsc::Sender<Event>,
     receiver: mpsc::Receiver<Event>,
+    #[allow(dead_code)]
     handler: thread::JoinHandle<()>,
 }
 
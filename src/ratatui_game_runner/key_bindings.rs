@@ -0,0 +1,389 @@
+use crate::config::{Config, ConfigError};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::{collections::HashMap, fmt, str::FromStr};
+
+const KEYS_SECTION: &str = "keys";
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub(crate) enum Action {
+    Exit,
+    Help,
+    Back,
+    Up,
+    Down,
+    Left,
+    Right,
+    Select,
+    Undo,
+    Redo,
+    Copy,
+}
+
+impl Action {
+    pub(crate) const ALL: [Action; 11] = [
+        Action::Exit,
+        Action::Help,
+        Action::Back,
+        Action::Up,
+        Action::Down,
+        Action::Left,
+        Action::Right,
+        Action::Select,
+        Action::Undo,
+        Action::Redo,
+        Action::Copy,
+    ];
+
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            Action::Exit => "exit",
+            Action::Help => "help",
+            Action::Back => "back",
+            Action::Up => "up",
+            Action::Down => "down",
+            Action::Left => "left",
+            Action::Right => "right",
+            Action::Select => "select",
+            Action::Undo => "undo",
+            Action::Redo => "redo",
+            Action::Copy => "copy",
+        }
+    }
+
+    fn default_bindings(&self) -> Vec<KeyBinding> {
+        let binding = match self {
+            Action::Exit => KeyBinding::new(KeyCode::Char('c'), KeyModifiers::CONTROL),
+            Action::Help => KeyBinding::new(KeyCode::Char('?'), KeyModifiers::NONE),
+            Action::Back => KeyBinding::new(KeyCode::Esc, KeyModifiers::NONE),
+            Action::Up => KeyBinding::new(KeyCode::Up, KeyModifiers::NONE),
+            Action::Down => KeyBinding::new(KeyCode::Down, KeyModifiers::NONE),
+            Action::Left => KeyBinding::new(KeyCode::Left, KeyModifiers::NONE),
+            Action::Right => KeyBinding::new(KeyCode::Right, KeyModifiers::NONE),
+            Action::Select => KeyBinding::new(KeyCode::Enter, KeyModifiers::NONE),
+            Action::Undo => KeyBinding::new(KeyCode::Char('z'), KeyModifiers::CONTROL),
+            Action::Redo => KeyBinding::new(KeyCode::Char('y'), KeyModifiers::CONTROL),
+            Action::Copy => KeyBinding::new(KeyCode::Char('c'), KeyModifiers::NONE),
+        };
+
+        vec![binding]
+    }
+
+    pub(crate) fn is_available_during_game(&self) -> bool {
+        matches!(
+            self,
+            Action::Exit
+                | Action::Help
+                | Action::Back
+                | Action::Select
+                | Action::Undo
+                | Action::Redo
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub(crate) struct KeyBinding {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self::normalize(code, modifiers)
+    }
+
+    fn from_event(key_event: KeyEvent) -> Self {
+        Self::normalize(key_event.code, key_event.modifiers)
+    }
+
+    fn normalize(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        match code {
+            KeyCode::Char(char)
+                if modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                KeyBinding {
+                    code: KeyCode::Char(char.to_ascii_lowercase()),
+                    modifiers: modifiers - KeyModifiers::SHIFT,
+                }
+            }
+            KeyCode::Char(_) => KeyBinding {
+                code,
+                modifiers: modifiers - KeyModifiers::SHIFT,
+            },
+            _ => KeyBinding { code, modifiers },
+        }
+    }
+
+    fn is_guess(&self) -> bool {
+        matches!(self.code, KeyCode::Char(char) if char.is_ascii_alphabetic())
+            && self.modifiers.is_empty()
+    }
+}
+
+impl FromStr for KeyBinding {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut modifiers = KeyModifiers::NONE;
+        let mut rest = value.trim();
+
+        while let Some((prefix, remainder)) = rest.split_once('-') {
+            let modifier = match prefix.to_lowercase().as_str() {
+                "ctrl" => KeyModifiers::CONTROL,
+                "alt" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                _ => break,
+            };
+
+            if remainder.is_empty() {
+                break;
+            }
+
+            modifiers |= modifier;
+            rest = remainder;
+        }
+
+        let code = match rest.to_lowercase().as_str() {
+            "esc" | "escape" => KeyCode::Esc,
+            "enter" | "return" => KeyCode::Enter,
+            "tab" => KeyCode::Tab,
+            "backspace" => KeyCode::Backspace,
+            "delete" | "del" => KeyCode::Delete,
+            "space" => KeyCode::Char(' '),
+            "comma" => KeyCode::Char(','),
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            lowercase => {
+                let mut chars = rest.chars();
+
+                match (chars.next(), chars.next()) {
+                    (Some(char), None) => KeyCode::Char(char),
+                    _ => match lowercase.strip_prefix('f').map(str::parse::<u8>) {
+                        Some(Ok(number @ 1..=12)) => KeyCode::F(number),
+                        _ => return Err(format!("unknown key \"{}\"", value.trim())),
+                    },
+                }
+            }
+        };
+
+        Ok(KeyBinding::new(code, modifiers))
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "Ctrl-")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "Alt-")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            write!(f, "Shift-")?;
+        }
+
+        match self.code {
+            KeyCode::Esc => write!(f, "Esc"),
+            KeyCode::Enter => write!(f, "Enter"),
+            KeyCode::Tab => write!(f, "Tab"),
+            KeyCode::Backspace => write!(f, "Backspace"),
+            KeyCode::Delete => write!(f, "Delete"),
+            KeyCode::Up => write!(f, "↑"),
+            KeyCode::Down => write!(f, "↓"),
+            KeyCode::Left => write!(f, "←"),
+            KeyCode::Right => write!(f, "→"),
+            KeyCode::Home => write!(f, "Home"),
+            KeyCode::End => write!(f, "End"),
+            KeyCode::PageUp => write!(f, "PageUp"),
+            KeyCode::PageDown => write!(f, "PageDown"),
+            KeyCode::F(number) => write!(f, "F{}", number),
+            KeyCode::Char(' ') => write!(f, "Space"),
+            KeyCode::Char(char) if self.modifiers.is_empty() => write!(f, "{}", char),
+            KeyCode::Char(char) => write!(f, "{}", char.to_ascii_uppercase()),
+            _ => write!(f, "?"),
+        }
+    }
+}
+
+pub(crate) struct KeyBindings {
+    bindings: HashMap<Action, Vec<KeyBinding>>,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        KeyBindings {
+            bindings: Action::ALL
+                .iter()
+                .map(|action| (*action, action.default_bindings()))
+                .collect(),
+        }
+    }
+}
+
+impl KeyBindings {
+    pub(crate) fn from_config(config: &Config) -> Result<Self, ConfigError> {
+        let mut key_bindings = KeyBindings::default();
+        let mut lines = HashMap::new();
+
+        for entry in config.section(KEYS_SECTION) {
+            let Some(action) = Action::ALL
+                .into_iter()
+                .find(|action| action.name() == entry.key)
+            else {
+                return Err(ConfigError::Value {
+                    line: entry.line,
+                    message: format!(
+                        "unknown action \"{}\", expected one of: {}",
+                        entry.key,
+                        Action::ALL.map(|action| action.name()).join(", ")
+                    ),
+                });
+            };
+
+            let bindings = entry
+                .value
+                .split(',')
+                .map(str::parse::<KeyBinding>)
+                .collect::<Result<Vec<KeyBinding>, String>>()
+                .map_err(|message| ConfigError::Value {
+                    line: entry.line,
+                    message,
+                })?;
+
+            if action.is_available_during_game() {
+                if let Some(binding) = bindings.iter().find(|binding| binding.is_guess()) {
+                    return Err(ConfigError::Value {
+                        line: entry.line,
+                        message: format!(
+                            "\"{}\" is used for guessing letters and cannot be bound to \"{}\"",
+                            binding,
+                            action.name()
+                        ),
+                    });
+                }
+            }
+
+            key_bindings.bindings.insert(action, bindings);
+            lines.insert(action, entry.line);
+        }
+
+        for (index, action) in Action::ALL.iter().enumerate() {
+            for other_action in &Action::ALL[index + 1..] {
+                let conflict = key_bindings.bindings[action]
+                    .iter()
+                    .find(|binding| key_bindings.bindings[other_action].contains(binding));
+
+                if let Some(binding) = conflict {
+                    return Err(ConfigError::Value {
+                        line: lines
+                            .get(action)
+                            .copied()
+                            .max(lines.get(other_action).copied())
+                            .unwrap_or_default(),
+                        message: format!(
+                            "\"{}\" is bound to both \"{}\" and \"{}\"",
+                            binding,
+                            action.name(),
+                            other_action.name()
+                        ),
+                    });
+                }
+            }
+        }
+
+        Ok(key_bindings)
+    }
+
+    pub(crate) fn action(&self, key_event: KeyEvent) -> Option<Action> {
+        let binding = KeyBinding::from_event(key_event);
+
+        Action::ALL
+            .into_iter()
+            .find(|action| self.bindings[action].contains(&binding))
+    }
+
+    pub(crate) fn describe(&self, actions: &[Action]) -> String {
+        actions
+            .iter()
+            .flat_map(|action| &self.bindings[action])
+            .map(|binding| binding.to_string())
+            .collect::<Vec<String>>()
+            .join("/")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_bindings_resolve_the_default_keys() {
+        let key_bindings = KeyBindings::default();
+
+        assert_eq!(
+            key_bindings.action(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)),
+            Some(Action::Exit)
+        );
+        assert_eq!(
+            key_bindings.action(KeyEvent::new(KeyCode::Char('?'), KeyModifiers::SHIFT)),
+            Some(Action::Help)
+        );
+        assert_eq!(
+            key_bindings.action(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE)),
+            None
+        );
+    }
+
+    #[test]
+    fn bindings_from_the_config_replace_the_defaults_for_that_action() {
+        let config = Config::parse("[keys]\nup = Up, k\nexit = Ctrl-Q\n").unwrap();
+
+        let key_bindings = KeyBindings::from_config(&config).expect("bindings should be valid");
+
+        assert_eq!(
+            key_bindings.action(KeyEvent::new(KeyCode::Char('k'), KeyModifiers::NONE)),
+            Some(Action::Up)
+        );
+        assert_eq!(
+            key_bindings.action(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)),
+            None
+        );
+        assert_eq!(key_bindings.describe(&[Action::Up, Action::Down]), "↑/k/↓");
+        assert_eq!(key_bindings.describe(&[Action::Exit]), "Ctrl-Q");
+    }
+
+    #[test]
+    fn binding_a_letter_to_an_action_used_during_a_game_returns_an_error() {
+        let config = Config::parse("[keys]\nback = q\n").unwrap();
+
+        assert!(matches!(
+            KeyBindings::from_config(&config),
+            Err(ConfigError::Value { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn binding_the_same_key_to_two_actions_returns_an_error() {
+        let config = Config::parse("[keys]\nselect = Enter\nback = Enter\n").unwrap();
+
+        assert!(matches!(
+            KeyBindings::from_config(&config),
+            Err(ConfigError::Value { line: 3, .. })
+        ));
+    }
+
+    #[test]
+    fn unknown_actions_and_keys_return_an_error() {
+        let unknown_action = Config::parse("[keys]\njump = Space\n").unwrap();
+        let unknown_key = Config::parse("[keys]\nup = Hyper\n").unwrap();
+
+        assert!(KeyBindings::from_config(&unknown_action).is_err());
+        assert!(KeyBindings::from_config(&unknown_key).is_err());
+    }
+}
@@ -1,10 +1,13 @@
-use crate::ratatui_game_runner::{app::App, event_handler::EventHandler, ui};
+use crate::ratatui_game_runner::{app::App, clipboard, event_handler::EventHandler, ui};
 use anyhow::Result;
 use crossterm::{
     event::{DisableMouseCapture, EnableMouseCapture},
     terminal::{self, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use std::panic;
+use std::{
+    io::{self, Write},
+    panic,
+};
 
 pub type Frame<'a> = ratatui::Frame<'a, ratatui::backend::CrosstermBackend<std::io::Stderr>>;
 pub type CrosstermTerminal = ratatui::Terminal<ratatui::backend::CrosstermBackend<std::io::Stderr>>;
@@ -42,6 +45,14 @@ impl Tui {
         Ok(())
     }
 
+    pub(crate) fn copy_to_clipboard(&mut self, text: &str) -> Result<()> {
+        let mut stderr = io::stderr();
+
+        write!(stderr, "{}", clipboard::osc52(text))?;
+        stderr.flush()?;
+        Ok(())
+    }
+
     pub(crate) fn exit(&mut self) -> Result<()> {
         Self::reset()?;
         self.terminal.show_cursor()?;
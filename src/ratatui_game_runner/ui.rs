@@ -1,13 +1,38 @@
+mod accessible_game;
+mod controls;
+mod daily_played;
+mod figure;
 mod game_complete;
 mod game_in_progress;
+mod game_modes;
+mod help;
+mod main_menu;
+mod settings;
 mod shared;
+mod statistics;
 
 use crate::ratatui_game_runner::{app::App, app::CurrentView, tui::Frame};
 
 pub(crate) fn render(app: &mut App, frame: &mut Frame) {
-    game_in_progress::render(app, frame);
+    app.menu_item_areas.clear();
 
-    if let CurrentView::GameComplete(_) = app.current_view {
-        game_complete::render(app, frame);
+    match app.current_view {
+        CurrentView::MainMenu(_) => main_menu::render(app, frame),
+        CurrentView::GameModes(_) => game_modes::render(app, frame),
+        CurrentView::Statistics => statistics::render(app, frame),
+        CurrentView::Settings(_) => settings::render(app, frame),
+        CurrentView::DailyPlayed => daily_played::render(app, frame),
+        CurrentView::GameInProgress | CurrentView::GameComplete(_) if app.settings.accessible => {
+            accessible_game::render(app, frame)
+        }
+        CurrentView::GameInProgress => game_in_progress::render(app, frame),
+        CurrentView::GameComplete(_) => {
+            game_in_progress::render(app, frame);
+            game_complete::render(app, frame);
+        }
+    }
+
+    if app.show_help {
+        help::render(app, frame);
     }
 }
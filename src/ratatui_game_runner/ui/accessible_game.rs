@@ -0,0 +1,117 @@
+use crate::{
+    accessibility,
+    i18n::fill,
+    messages,
+    ratatui_game_runner::{
+        app::{App, CurrentView, Feedback, PlayAgain},
+        tui::Frame,
+        ui::{controls, daily_played, game_in_progress},
+    },
+};
+use ratatui::{
+    layout::Margin,
+    text::Line,
+    widgets::{Paragraph, Wrap},
+};
+
+/// Renders the game as plain sentences, one per line, without borders, colours or animations so
+/// that screen readers can read it from top to bottom.
+pub(crate) fn render(app: &mut App, frame: &mut Frame) {
+    let locale = app.settings.locale;
+    let text = locale.catalogue();
+    let game = &app.current_game;
+
+    let mut lines = vec![
+        Line::from(fill(
+            text.accessible_header,
+            &[
+                ("played", &app.games_played),
+                ("won", &app.games_won),
+                ("lost", &app.games_lost),
+            ],
+        )),
+        Line::from(""),
+        Line::from(format!("{}.", accessibility::describe_word(locale, game))),
+    ];
+
+    if let Some(entry) = game.entry() {
+        lines.push(Line::from(format!(
+            "{}.",
+            fill(text.category_label, &[("category", &entry.category)])
+        )));
+    }
+
+    if let Some(clue) = messages::clue(locale, game) {
+        lines.push(Line::from(clue));
+    }
+
+    lines.push(Line::from(accessibility::describe_guesses(locale, game)));
+
+    if let Some(last_move) = &app.last_move {
+        let (_, message) = last_move.feedback.message(locale);
+        let message = accessibility::without_glyph(&message);
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(match last_move.feedback {
+            Feedback::Guess { .. } => fill(text.last_guess, &[("message", &message)]),
+            Feedback::Undo { .. } | Feedback::Redo(_) => String::from(message),
+        }));
+    }
+
+    if let Some(letter) = &app.selected_letter {
+        lines.push(Line::from(""));
+        lines.push(Line::from(game_in_progress::letter_selected_message(
+            app, letter,
+        )));
+    }
+
+    if let Some(description) = accessibility::describe_complete_game(locale, game) {
+        lines.push(Line::from(""));
+        lines.push(Line::from(description));
+        lines.extend(
+            messages::definition(locale, game)
+                .into_iter()
+                .map(Line::from),
+        );
+
+        if app.is_daily_game {
+            lines.push(Line::from(""));
+            lines.push(Line::from(text.share_result));
+            lines.extend(daily_played::result_lines(app).into_iter().map(Line::from));
+        }
+    }
+
+    if let CurrentView::GameComplete(game_complete_state) = &app.current_view {
+        let choice = match game_complete_state.currently_selected {
+            PlayAgain::Yes => text.yes,
+            PlayAgain::No => text.no,
+        };
+
+        lines.push(Line::from(fill(
+            text.play_again_selected,
+            &[("choice", &choice)],
+        )));
+    }
+
+    let controls = controls::for_view(app)
+        .iter()
+        .map(|(control, action)| {
+            fill(
+                text.control_sentence,
+                &[("key", &control.as_str()), ("action", action)],
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(fill(
+        text.controls_sentence,
+        &[("controls", &controls)],
+    )));
+
+    frame.render_widget(
+        Paragraph::new(lines).wrap(Wrap { trim: false }),
+        frame.size().inner(&Margin::new(1, 1)),
+    );
+}
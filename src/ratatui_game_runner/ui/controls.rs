@@ -0,0 +1,87 @@
+use crate::ratatui_game_runner::{
+    app::{App, CurrentView},
+    key_bindings::Action,
+};
+
+pub(crate) fn for_view(app: &App) -> Vec<(String, &'static str)> {
+    let text = app.settings.locale.catalogue();
+    let controls: Vec<(&[Action], &str)> = match app.current_view {
+        CurrentView::MainMenu(_) => vec![
+            (&[Action::Exit], text.exit),
+            (&[Action::Back], text.quit),
+            (&[Action::Up, Action::Down], text.move_selection),
+            (&[Action::Select], text.select),
+            (&[Action::Help], text.help),
+        ],
+        CurrentView::GameModes(_) => vec![
+            (&[Action::Back], text.back),
+            (&[Action::Up, Action::Down], text.move_selection),
+            (&[Action::Select], text.select),
+            (&[Action::Help], text.help),
+        ],
+        CurrentView::Statistics => vec![(&[Action::Back], text.back), (&[Action::Help], text.help)],
+        CurrentView::Settings(_) => vec![
+            (&[Action::Back], text.back),
+            (&[Action::Up, Action::Down], text.move_selection),
+            (&[Action::Left, Action::Right], text.change),
+            (&[Action::Help], text.help),
+        ],
+        CurrentView::GameInProgress if app.settings.confirm_guesses => vec![
+            (&[Action::Exit], text.exit),
+            (&[Action::Back], text.menu),
+            (&[], text.select_letter),
+            (&[Action::Select], text.confirm),
+            (&[Action::Undo], text.undo),
+            (&[Action::Redo], text.redo),
+            (&[Action::Help], text.help),
+        ],
+        CurrentView::GameInProgress => vec![
+            (&[Action::Exit], text.exit),
+            (&[Action::Back], text.menu),
+            (&[], text.make_guess),
+            (&[Action::Undo], text.undo),
+            (&[Action::Redo], text.redo),
+            (&[Action::Help], text.help),
+        ],
+        CurrentView::GameComplete(_) if app.is_daily_game => vec![
+            (&[Action::Exit], text.exit),
+            (&[Action::Back], text.menu),
+            (&[Action::Left, Action::Right], text.move_selection),
+            (&[Action::Select], text.select),
+            (&[Action::Copy], text.copy_result),
+            (&[Action::Help], text.help),
+        ],
+        CurrentView::GameComplete(_) => vec![
+            (&[Action::Exit], text.exit),
+            (&[Action::Back], text.menu),
+            (&[Action::Left, Action::Right], text.move_selection),
+            (&[Action::Select], text.select),
+            (&[Action::Help], text.help),
+        ],
+        CurrentView::DailyPlayed => vec![
+            (&[Action::Back], text.back),
+            (&[Action::Copy], text.copy_result),
+            (&[Action::Help], text.help),
+        ],
+    };
+
+    controls
+        .into_iter()
+        .map(|(actions, description)| {
+            if actions.is_empty() {
+                (String::from("A-Z"), description)
+            } else {
+                (app.key_bindings.describe(actions), description)
+            }
+        })
+        .collect()
+}
+
+pub(crate) fn for_help(app: &App) -> Vec<(String, &'static str)> {
+    let text = app.settings.locale.catalogue();
+
+    vec![(
+        app.key_bindings.describe(&[Action::Back, Action::Help]),
+        text.close_help,
+    )]
+}
@@ -0,0 +1,66 @@
+use crate::{
+    messages,
+    ratatui_game_runner::{
+        app::App,
+        tui::Frame,
+        ui::{controls, shared},
+    },
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    text::Line,
+    widgets::{Paragraph, Wrap},
+};
+
+pub(crate) fn render(app: &mut App, frame: &mut Frame) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(frame.size());
+
+    let text = app.settings.locale.catalogue();
+    let result_lines = result_lines(app);
+    let inner_rect = shared::render_block(
+        frame,
+        shared::centered_rect(50, 5 + result_lines.len() as u16, chunks[0]),
+        text.daily,
+    );
+
+    let vertical_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(1)])
+        .split(inner_rect);
+
+    frame.render_widget(
+        Paragraph::new(text.daily_already_played)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true }),
+        vertical_chunks[0],
+    );
+
+    frame.render_widget(
+        Paragraph::new(
+            result_lines
+                .into_iter()
+                .map(Line::from)
+                .collect::<Vec<Line>>(),
+        )
+        .alignment(Alignment::Center),
+        vertical_chunks[1],
+    );
+
+    shared::render_controls(frame, chunks[1], &controls::for_view(app));
+}
+
+/// The shareable result of today's word, followed by any notice such as it having been copied.
+pub(crate) fn result_lines(app: &App) -> Vec<String> {
+    let mut lines = app
+        .daily_records
+        .get(app.today)
+        .map(|record| messages::daily_share(app.settings.locale, record))
+        .unwrap_or_default();
+
+    lines.extend(app.daily_notice.clone());
+
+    lines
+}
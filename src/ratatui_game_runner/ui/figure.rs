@@ -0,0 +1,133 @@
+use crate::ratatui_game_runner::{
+    animation::AnimationKind,
+    app::{App, CurrentView},
+    tui::Frame,
+    ui::shared,
+};
+use ratatui::{
+    layout::{Alignment, Rect},
+    text::Line,
+    widgets::Paragraph,
+};
+
+const STAGES: usize = 10;
+const WIDTH: usize = 9;
+const HEIGHT: usize = 7;
+const SWING_OFFSETS: [isize; 4] = [0, 1, 0, -1];
+
+pub(crate) fn render(app: &App, frame: &mut Frame, area: Rect) {
+    let inner_rect = shared::render_block(frame, area, app.settings.locale.catalogue().gallows);
+
+    let stage = stage(app.current_game.lives(), app.current_game.lives_remaining());
+
+    let swing_offset = match app.animations.find(|kind| *kind == AnimationKind::Swing) {
+        Some((_, frame)) if matches!(app.current_view, CurrentView::GameComplete(_)) => {
+            SWING_OFFSETS[frame as usize % SWING_OFFSETS.len()]
+        }
+        _ => 0,
+    };
+
+    frame.render_widget(
+        Paragraph::new(
+            lines(stage, swing_offset)
+                .into_iter()
+                .map(Line::from)
+                .collect::<Vec<Line>>(),
+        )
+        .alignment(Alignment::Center),
+        inner_rect,
+    );
+}
+
+/// Lives can be lost to wrong guesses, hints, wrong solves or undos, so the stage follows the
+/// lives remaining rather than the guesses.
+fn stage(lives: usize, lives_remaining: usize) -> usize {
+    (lives.saturating_sub(lives_remaining) * STAGES).div_ceil(lives.max(1))
+}
+
+fn lines(stage: usize, swing_offset: isize) -> Vec<String> {
+    let mut grid = [[' '; WIDTH]; HEIGHT];
+    let mut draw = |minimum_stage: usize, row: usize, column: isize, char: char| {
+        if stage >= minimum_stage {
+            grid[row][column as usize] = char;
+        }
+    };
+
+    let body = 2 + swing_offset;
+
+    (0..WIDTH).for_each(|column| draw(1, 6, column as isize, '='));
+    (1..6).for_each(|row| draw(2, row, 7, '|'));
+    (2..7).for_each(|column| draw(3, 0, column, '-'));
+    draw(3, 0, 2, '+');
+    draw(3, 0, 7, '+');
+    draw(
+        4,
+        1,
+        2,
+        match swing_offset {
+            1 => '\\',
+            -1 => '/',
+            _ => '|',
+        },
+    );
+    draw(5, 2, body, 'O');
+    draw(6, 3, body, '|');
+    draw(7, 3, body - 1, '/');
+    draw(8, 3, body + 1, '\\');
+    draw(9, 4, body - 1, '/');
+    draw(10, 4, body + 1, '\\');
+
+    grid.iter().map(|row| row.iter().collect()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hangman::{GameBuilder, Rules};
+
+    #[test]
+    fn the_complete_figure_is_drawn_at_the_final_stage() {
+        assert_eq!(
+            lines(STAGES, 0),
+            vec![
+                "  +----+ ",
+                "  |    | ",
+                "  O    | ",
+                " /|\\   | ",
+                " / \\   | ",
+                "       | ",
+                "=========",
+            ]
+        );
+    }
+
+    #[test]
+    fn nothing_is_drawn_before_any_lives_are_lost() {
+        assert!(lines(0, 0).iter().all(|line| line.trim().is_empty()));
+    }
+
+    #[test]
+    fn lives_spent_on_undos_move_the_figure_on() {
+        let mut game = GameBuilder::new()
+            .word("cat")
+            .lives(10)
+            .rules(Rules::new().undo_cost(2))
+            .build()
+            .unwrap();
+
+        game.make_guess("z").unwrap();
+        game.make_guess("q").unwrap();
+        game.undo().unwrap();
+        game.undo().unwrap();
+
+        assert!(game.guesses().is_empty());
+        assert_eq!(game.lives_remaining(), 6);
+        assert_eq!(stage(game.lives(), game.lives_remaining()), 4);
+    }
+
+    #[test]
+    fn the_figure_is_complete_when_no_lives_remain() {
+        assert_eq!(stage(3, 0), STAGES);
+        assert_eq!(stage(1, 0), STAGES);
+    }
+}
@@ -1,48 +1,92 @@
 use crate::{
-    game::{CompleteGameStatus, GameStatus},
+    messages,
     ratatui_game_runner::{
+        animation::AnimationKind,
         app::{App, CurrentView, PlayAgain},
         tui::Frame,
-        ui::shared,
+        ui::{controls, daily_played, shared},
     },
 };
+use hangman::{CompleteGameStatus, GameStatus};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
-    style::{Color, Style},
+    style::{Color, Modifier, Style, Stylize},
     text::{Line, Span},
-    widgets::{Clear, Paragraph},
+    widgets::{Clear, Paragraph, Wrap},
 };
 
+const CELEBRATION_COLORS: [Color; 4] = [Color::Green, Color::Yellow, Color::Cyan, Color::Magenta];
+const SPARKLES: [&str; 4] = ["*", " ", "+", " "];
+const SPARKLE_COUNT: usize = 21;
+const DIALOG_WIDTH: u16 = 60;
+const DIALOG_HEIGHT: u16 = 9;
+// The border, padding and margin on either side of the dialog.
+const DIALOG_PADDING: u16 = 6;
+
 pub(crate) fn render(app: &mut App, frame: &mut Frame) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Min(1), Constraint::Length(1)])
         .split(frame.size());
 
-    render_complete_game_dialog(app, frame, shared::centered_rect(50, 9, chunks[0]));
+    let definition = messages::definition(app.settings.locale, &app.current_game);
+    let daily_result = if app.is_daily_game {
+        daily_played::result_lines(app)
+    } else {
+        Vec::new()
+    };
+    let extra_height = section_height(&definition) + section_height(&daily_result);
+
+    render_complete_game_dialog(
+        app,
+        frame,
+        [&definition, &daily_result],
+        shared::centered_rect(DIALOG_WIDTH, DIALOG_HEIGHT + extra_height, chunks[0]),
+    );
 
-    render_controls(frame, chunks[1]);
+    render_controls(app, frame, chunks[1]);
 }
 
-fn render_complete_game_dialog(app: &mut App, frame: &mut Frame, area: Rect) {
+/// Renders the dialog with the definition and the daily result, if there are any, between the
+/// word and the question to play again.
+fn render_complete_game_dialog(
+    app: &mut App,
+    frame: &mut Frame,
+    sections: [&[String]; 2],
+    area: Rect,
+) {
     frame.render_widget(Clear, area);
 
     let GameStatus::Complete(complete_game_status) = &app.current_game.status() else {
         unreachable!("this dialog is only shown when game is complete");
     };
 
+    let text = app.settings.locale.catalogue();
     let (title, color) = match complete_game_status {
-        CompleteGameStatus::Won => ("You Won!", Color::Green),
-        CompleteGameStatus::Lost => ("You Lost...", Color::Red),
+        CompleteGameStatus::Won => (text.you_won, Color::Green),
+        CompleteGameStatus::Lost => (text.you_lost, Color::Red),
     };
 
-    let inner_block = shared::render_styled_block(frame, area, title, Style::default().fg(color))
-        .inner(&Margin::new(1, 1));
+    let celebration_frame = app
+        .animations
+        .find(|kind| *kind == AnimationKind::Celebrate)
+        .map(|(_, frame)| frame as usize);
+
+    let border_color = match celebration_frame {
+        Some(frame) => CELEBRATION_COLORS[frame % CELEBRATION_COLORS.len()],
+        None => color,
+    };
+
+    let inner_block =
+        shared::render_styled_block(frame, area, title, Style::default().fg(border_color))
+            .inner(&Margin::new(1, 1));
 
     let vertical_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(2),
+            Constraint::Length(section_height(sections[0])),
+            Constraint::Length(section_height(sections[1])),
             Constraint::Length(2),
             Constraint::Length(1),
         ])
@@ -51,19 +95,38 @@ fn render_complete_game_dialog(app: &mut App, frame: &mut Frame, area: Rect) {
     let horizontal_chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .split(vertical_chunks[2]);
+        .split(vertical_chunks[4]);
+
+    let mut word_lines = vec![Line::from(app.current_game.letters().join(" "))];
+
+    if let Some(frame) = celebration_frame {
+        word_lines.push(Line::from(
+            (0..SPARKLE_COUNT)
+                .map(|index| {
+                    Span::raw(SPARKLES[(index + frame) % SPARKLES.len()])
+                        .fg(CELEBRATION_COLORS[(index + frame) % CELEBRATION_COLORS.len()])
+                })
+                .collect::<Vec<Span>>(),
+        ));
+    }
 
     frame.render_widget(
-        Paragraph::new(format!("{}", app.current_game.letters().join(" ")))
-            .alignment(Alignment::Center),
+        Paragraph::new(word_lines).alignment(Alignment::Center),
         vertical_chunks[0],
     );
 
     frame.render_widget(
-        Paragraph::new("Would you like to play again?").alignment(Alignment::Center),
+        section_paragraph(sections[0]).add_modifier(Modifier::ITALIC),
         vertical_chunks[1],
     );
 
+    frame.render_widget(section_paragraph(sections[1]), vertical_chunks[2]);
+
+    frame.render_widget(
+        Paragraph::new(text.play_again).alignment(Alignment::Center),
+        vertical_chunks[3],
+    );
+
     let CurrentView::GameComplete(game_complete_state) = &app.current_view else {
         unreachable!("this dialog is only shown when the current view is GameComplete");
     };
@@ -71,7 +134,7 @@ fn render_complete_game_dialog(app: &mut App, frame: &mut Frame, area: Rect) {
     let selected_style = Style::default().bg(color).fg(Color::Black);
 
     frame.render_widget(
-        Paragraph::new("Yes").alignment(Alignment::Center).style(
+        Paragraph::new(text.yes).alignment(Alignment::Center).style(
             match game_complete_state.currently_selected {
                 PlayAgain::Yes => selected_style,
                 PlayAgain::No => Style::default(),
@@ -80,7 +143,7 @@ fn render_complete_game_dialog(app: &mut App, frame: &mut Frame, area: Rect) {
         horizontal_chunks[0],
     );
     frame.render_widget(
-        Paragraph::new("No").alignment(Alignment::Center).style(
+        Paragraph::new(text.no).alignment(Alignment::Center).style(
             match game_complete_state.currently_selected {
                 PlayAgain::Yes => Style::default(),
                 PlayAgain::No => selected_style,
@@ -90,21 +153,53 @@ fn render_complete_game_dialog(app: &mut App, frame: &mut Frame, area: Rect) {
     );
 }
 
-fn render_controls(frame: &mut Frame, area: Rect) {
-    frame.render_widget(Clear, area);
+fn section_paragraph(lines: &[String]) -> Paragraph<'_> {
+    Paragraph::new(
+        lines
+            .iter()
+            .map(|line| Line::from(line.as_str()))
+            .collect::<Vec<Line>>(),
+    )
+    .alignment(Alignment::Center)
+    .wrap(Wrap { trim: true })
+}
 
-    let spans: Vec<Span> = vec![
-        shared::control_span("Esc/Ctrl-C", "Exit"),
-        shared::control_span("←/→", "Move"),
-        shared::control_span("Enter", "Select"),
-    ]
-    .iter()
-    .flatten()
-    .map(|x| x.clone())
-    .collect();
+/// The rows a section of the dialog takes up, including a blank row after it if it isn't empty.
+fn section_height(lines: &[String]) -> u16 {
+    match wrapped_height(lines, DIALOG_WIDTH - DIALOG_PADDING) {
+        0 => 0,
+        height => height + 1,
+    }
+}
 
-    frame.render_widget(
-        Paragraph::new(Line::from(spans)).alignment(Alignment::Center),
-        area,
-    );
+/// The number of rows the lines take up when wrapped at word boundaries to a width.
+fn wrapped_height(lines: &[String], width: u16) -> u16 {
+    let width = usize::from(width);
+
+    lines
+        .iter()
+        .map(|line| {
+            let mut rows = 1;
+            let mut row_width = 0;
+
+            for word_width in line.split_whitespace().map(|word| word.chars().count()) {
+                if row_width > 0 && row_width + 1 + word_width > width {
+                    rows += 1;
+                    row_width = word_width;
+                } else if row_width > 0 {
+                    row_width += 1 + word_width;
+                } else {
+                    row_width = word_width;
+                }
+            }
+
+            rows
+        })
+        .sum()
+}
+
+fn render_controls(app: &App, frame: &mut Frame, area: Rect) {
+    frame.render_widget(Clear, area);
+
+    shared::render_controls(frame, area, &controls::for_view(app));
 }
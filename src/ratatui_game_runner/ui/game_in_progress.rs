@@ -1,16 +1,20 @@
 use crate::{
-    game::{allowed_letters::ALLOWED_LETTER_RANGE, GuessStatus},
+    i18n::fill,
+    messages::{self, Tone},
     ratatui_game_runner::{
+        animation::AnimationKind,
         app::{App, CurrentView},
+        key_bindings::Action,
         tui::Frame,
-        ui::shared,
+        ui::{controls, figure, shared},
     },
 };
+use hangman::{game::ALLOWED_LETTER_RANGE, GuessStatus};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
     style::{Color, Modifier, Style, Stylize},
     text::{Line, Span},
-    widgets::Paragraph,
+    widgets::{Paragraph, Wrap},
 };
 
 pub(crate) fn render(app: &mut App, frame: &mut Frame) {
@@ -19,6 +23,7 @@ pub(crate) fn render(app: &mut App, frame: &mut Frame) {
             Constraint::Length(3),
             Constraint::Min(10),
             Constraint::Length(1),
+            Constraint::Length(1),
         ])
         .split(frame.size());
 
@@ -26,22 +31,29 @@ pub(crate) fn render(app: &mut App, frame: &mut Frame) {
 
     render_current_game_and_guesses(app, frame, chunks[1]);
 
-    render_controls(frame, chunks[2]);
+    render_status_bar(app, frame, chunks[2]);
+
+    render_controls(app, frame, chunks[3]);
 }
 
 fn render_header(app: &mut App, frame: &mut Frame, area: Rect) {
-    let inner_rect = render_block(app, frame, area, "Hangman");
+    let text = app.settings.locale.catalogue();
+    let inner_rect = render_block(app, frame, area, text.hangman);
 
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Min(20), Constraint::Min(20)])
         .split(inner_rect);
 
-    frame.render_widget(Paragraph::new("Welcome to Hangman!"), chunks[0]);
+    frame.render_widget(Paragraph::new(text.welcome), chunks[0]);
     frame.render_widget(
-        Paragraph::new(format!(
-            "Played: {}, Won: {}, Lost: {}",
-            app.games_played, app.games_won, app.games_lost
+        Paragraph::new(fill(
+            text.header_statistics,
+            &[
+                ("played", &app.games_played),
+                ("won", &app.games_won),
+                ("lost", &app.games_lost),
+            ],
         ))
         .alignment(Alignment::Right),
         chunks[1],
@@ -71,46 +83,77 @@ fn render_current_game_and_remaining_letters(app: &mut App, frame: &mut Frame, a
 }
 
 fn render_current_game(app: &mut App, frame: &mut Frame, area: Rect) {
-    let inner_rect = render_block(app, frame, area, "Current Game").inner(&Margin::new(1, 1));
+    let text = app.settings.locale.catalogue();
+    let inner_rect = render_block(app, frame, area, text.current_game).inner(&Margin::new(1, 1));
+
+    if let Some(entry) = app.current_game.entry() {
+        let lines = [fill(text.category_label, &[("category", &entry.category)])]
+            .into_iter()
+            .chain(messages::clue(app.settings.locale, &app.current_game))
+            .map(Line::from)
+            .collect::<Vec<Line>>();
+
+        frame.render_widget(
+            Paragraph::new(lines)
+                .alignment(Alignment::Center)
+                .wrap(Wrap { trim: true })
+                .add_modifier(Modifier::DIM),
+            inner_rect,
+        );
+    }
+
+    let revealing_positions = match app
+        .animations
+        .find(|kind| matches!(kind, AnimationKind::RevealLetters(_)))
+    {
+        Some((AnimationKind::RevealLetters(positions), frame)) => Some((positions, frame)),
+        _ => None,
+    };
 
-    let current_word_state = format!("{}", app.current_game.blanked_out_letters().join(" "));
+    let letter_spans = app
+        .current_game
+        .blanked_out_letters()
+        .into_iter()
+        .enumerate()
+        .map(|(index, letter)| match revealing_positions {
+            Some((positions, 0)) if positions.contains(&index) => {
+                Span::raw("\u{2594}").fg(Color::Green)
+            }
+            Some((positions, _)) if positions.contains(&index) => Span::raw(letter)
+                .fg(Color::Green)
+                .add_modifier(Modifier::BOLD),
+            _ => Span::raw(letter),
+        })
+        .collect::<Vec<Span>>();
 
-    render_current_game_state(frame, inner_rect, current_word_state);
+    render_current_game_state(
+        frame,
+        inner_rect,
+        Line::from(shared::intersperse(letter_spans, Span::raw(" "))),
+    );
 }
 
-fn render_current_game_state(frame: &mut Frame, area: Rect, current_word_state: String) {
+fn render_current_game_state(frame: &mut Frame, area: Rect, current_word_state: Line) {
     let current_word_state_length = current_word_state
-        .len()
+        .width()
         .try_into()
         .expect("word length should never be greater than u16 length");
 
     let vertical_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Min((area.height.checked_sub(1).unwrap_or(0)) / 2),
+            Constraint::Min(area.height.saturating_sub(1) / 2),
             Constraint::Length(1),
-            Constraint::Min((area.height.checked_sub(1).unwrap_or(0)) / 2),
+            Constraint::Min(area.height.saturating_sub(1) / 2),
         ])
         .split(area);
 
     let horizontal_chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([
-            Constraint::Min(
-                (area
-                    .width
-                    .checked_sub(current_word_state_length)
-                    .unwrap_or(0))
-                    / 2,
-            ),
+            Constraint::Min(area.width.saturating_sub(current_word_state_length) / 2),
             Constraint::Length(current_word_state_length),
-            Constraint::Min(
-                (area
-                    .width
-                    .checked_sub(current_word_state_length)
-                    .unwrap_or(0))
-                    / 2,
-            ),
+            Constraint::Min(area.width.saturating_sub(current_word_state_length) / 2),
         ])
         .split(vertical_chunks[1]);
 
@@ -118,7 +161,13 @@ fn render_current_game_state(frame: &mut Frame, area: Rect, current_word_state:
 }
 
 fn render_remaining_letters(app: &App, frame: &mut Frame, area: Rect) {
-    let inner_rect = render_block(app, frame, area, "Remaining Letters").inner(&Margin::new(1, 1));
+    let inner_rect = render_block(
+        app,
+        frame,
+        area,
+        app.settings.locale.catalogue().remaining_letters,
+    )
+    .inner(&Margin::new(1, 1));
 
     let guesses = app.current_game.guesses();
 
@@ -126,7 +175,15 @@ fn render_remaining_letters(app: &App, frame: &mut Frame, area: Rect) {
         ALLOWED_LETTER_RANGE
             .map(|x| x.to_string())
             .filter(|x| !guesses.iter().any(|(guess, _)| x == guess))
-            .map(|x| Span::raw(x).add_modifier(Modifier::BOLD))
+            .map(|x| {
+                let span = Span::raw(x.clone()).add_modifier(Modifier::BOLD);
+
+                if app.selected_letter.as_ref() == Some(&x) {
+                    span.fg(Color::Black).bg(Color::Yellow)
+                } else {
+                    span
+                }
+            })
             .collect::<Vec<Span>>(),
         Span::raw(" "),
     );
@@ -140,16 +197,23 @@ fn render_remaining_letters(app: &App, frame: &mut Frame, area: Rect) {
 fn render_lives_and_guesses(app: &mut App, frame: &mut Frame, area: Rect) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Length(4), Constraint::Min(5)])
+        .constraints([
+            Constraint::Length(4),
+            Constraint::Length(9),
+            Constraint::Min(5),
+        ])
         .split(area);
 
     render_lives(app, frame, chunks[0]);
 
-    render_guesses(app, frame, chunks[1]);
+    figure::render(app, frame, chunks[1]);
+
+    render_guesses(app, frame, chunks[2]);
 }
 
 fn render_lives(app: &mut App, frame: &mut Frame, area: Rect) {
-    let inner_rect = render_block(app, frame, area, "Lives");
+    let locale = app.settings.locale;
+    let inner_rect = render_block(app, frame, area, locale.catalogue().lives);
 
     let lives_remaining = app.current_game.lives_remaining();
 
@@ -159,14 +223,20 @@ fn render_lives(app: &mut App, frame: &mut Frame, area: Rect) {
         1..=5 => lives_remaining_span.fg(Color::Yellow),
         _ => lives_remaining_span.fg(Color::Green),
     };
+    let lives_remaining_span = match app.animations.find(|kind| *kind == AnimationKind::LifeLost) {
+        Some((_, frame)) if frame % 2 == 0 => lives_remaining_span.fg(Color::White).bg(Color::Red),
+        _ => lives_remaining_span,
+    };
 
     frame.render_widget(
         Paragraph::new(vec![
             Line::from(lives_remaining_span.add_modifier(Modifier::BOLD)),
             Line::from(
-                Span::raw("lives remaining")
-                    .add_modifier(Modifier::DIM)
-                    .add_modifier(Modifier::ITALIC),
+                Span::raw(
+                    locale.select(&locale.catalogue().lives_remaining_label, lives_remaining),
+                )
+                .add_modifier(Modifier::DIM)
+                .add_modifier(Modifier::ITALIC),
             ),
         ])
         .alignment(Alignment::Center),
@@ -175,7 +245,7 @@ fn render_lives(app: &mut App, frame: &mut Frame, area: Rect) {
 }
 
 fn render_guesses(app: &mut App, frame: &mut Frame, area: Rect) {
-    let inner_rect = render_block(app, frame, area, "Guesses");
+    let inner_rect = render_block(app, frame, area, app.settings.locale.catalogue().guesses);
 
     let guesses = app.current_game.guesses();
 
@@ -205,22 +275,62 @@ fn render_guesses(app: &mut App, frame: &mut Frame, area: Rect) {
     );
 }
 
-fn render_controls(frame: &mut Frame, area: Rect) {
-    let spans: Vec<Span> = vec![
-        shared::control_span("Esc/Ctrl-C", "Exit"),
-        shared::control_span("A-Z", "Make Guess"),
-    ]
-    .iter()
-    .flatten()
-    .map(|x| x.clone())
-    .collect();
+fn render_status_bar(app: &App, frame: &mut Frame, area: Rect) {
+    if let Some(letter) = &app.selected_letter {
+        frame.render_widget(
+            Paragraph::new(letter_selected_message(app, letter))
+                .alignment(Alignment::Center)
+                .style(Style::default().fg(Color::Yellow)),
+            area,
+        );
+
+        return;
+    }
+
+    let Some(last_move) = &app.last_move else {
+        return;
+    };
+
+    let (tone, message) = last_move.feedback.message(app.settings.locale);
+
+    let style = Style::default()
+        .fg(match tone {
+            Tone::Positive => Color::Green,
+            Tone::Negative => Color::Red,
+        })
+        .add_modifier(Modifier::BOLD);
+
+    let style =
+        if last_move.ticks_remaining <= App::LAST_MOVE_FADE_TICKS && !app.settings.reduced_motion {
+            style
+                .remove_modifier(Modifier::BOLD)
+                .add_modifier(Modifier::DIM)
+        } else {
+            style
+        };
 
     frame.render_widget(
-        Paragraph::new(Line::from(spans)).alignment(Alignment::Center),
+        Paragraph::new(message)
+            .alignment(Alignment::Center)
+            .style(style),
         area,
     );
 }
 
+pub(crate) fn letter_selected_message(app: &App, letter: &str) -> String {
+    fill(
+        app.settings.locale.catalogue().letter_selected,
+        &[
+            ("key", &app.key_bindings.describe(&[Action::Select])),
+            ("letter", &letter),
+        ],
+    )
+}
+
+fn render_controls(app: &App, frame: &mut Frame, area: Rect) {
+    shared::render_controls(frame, area, &controls::for_view(app));
+}
+
 fn render_block(app: &App, frame: &mut Frame, area: Rect, title: &str) -> Rect {
     let style = if app.current_view != CurrentView::GameInProgress {
         Style::default().add_modifier(Modifier::DIM)
@@ -0,0 +1,71 @@
+use crate::{
+    i18n::fill,
+    ratatui_game_runner::{
+        app::{App, CurrentView, GameMode},
+        tui::Frame,
+        ui::{controls, shared},
+    },
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    widgets::{Paragraph, Wrap},
+};
+
+pub(crate) fn render(app: &mut App, frame: &mut Frame) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(frame.size());
+
+    let CurrentView::GameModes(game_modes_state) = &app.current_view else {
+        unreachable!("game modes are only shown when the current view is GameModes");
+    };
+
+    let text = app.settings.locale.catalogue();
+    let inner_rect = shared::render_block(
+        frame,
+        shared::centered_rect(50, 12, chunks[0]),
+        text.game_modes,
+    );
+
+    let vertical_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(GameMode::ALL.len() as u16),
+            Constraint::Length(1),
+            Constraint::Min(1),
+        ])
+        .split(inner_rect);
+
+    let labels = GameMode::ALL.map(|game_mode| {
+        if game_mode == app.game_mode {
+            fill(text.active_game_mode, &[("mode", &game_mode.label(text))])
+        } else {
+            game_mode.label(text).to_string()
+        }
+    });
+
+    let selected_index = GameMode::ALL
+        .iter()
+        .position(|game_mode| *game_mode == game_modes_state.currently_selected)
+        .expect("selected item should be a game mode");
+
+    frame.render_widget(
+        Paragraph::new(game_modes_state.currently_selected.description(text))
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true })
+            .style(Style::default().add_modifier(Modifier::ITALIC)),
+        vertical_chunks[2],
+    );
+
+    app.menu_item_areas = shared::render_menu(
+        frame,
+        vertical_chunks[0],
+        &labels.each_ref().map(|label| label.as_str()),
+        selected_index,
+        app.settings.accessible,
+    );
+
+    shared::render_controls(frame, chunks[1], &controls::for_view(app));
+}
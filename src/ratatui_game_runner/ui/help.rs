@@ -0,0 +1,69 @@
+use crate::{
+    ratatui_game_runner::{app::App, tui::Frame, ui::controls, ui::shared},
+    rules::rules,
+};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Stylize},
+    text::{Line, Span},
+    widgets::{Clear, Paragraph, Wrap},
+};
+
+pub(crate) fn render(app: &mut App, frame: &mut Frame) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(frame.size());
+
+    render_help_dialog(app, frame, shared::centered_rect(70, 22, chunks[0]));
+
+    frame.render_widget(Clear, chunks[1]);
+    shared::render_controls(frame, chunks[1], &controls::for_help(app));
+}
+
+fn render_help_dialog(app: &mut App, frame: &mut Frame, area: Rect) {
+    frame.render_widget(Clear, area);
+
+    let text = app.settings.locale.catalogue();
+    let inner_rect = shared::render_block(frame, area, text.help);
+
+    let mut lines = vec![heading(text.rules)];
+
+    lines.extend(
+        rules(&app.settings, app.game_mode_lives())
+            .into_iter()
+            .map(|rule| Line::from(format!("- {}", rule))),
+    );
+
+    lines.extend([
+        Line::default(),
+        heading(text.game_mode),
+        Line::from(vec![
+            Span::raw(app.game_mode.label(text)).add_modifier(Modifier::BOLD),
+            Span::raw(format!(" - {}", app.game_mode.description(text))),
+        ]),
+        Line::default(),
+        heading(text.controls),
+    ]);
+
+    lines.extend(
+        controls::for_view(app)
+            .into_iter()
+            .map(|(control, action)| {
+                Line::from(vec![
+                    Span::raw(format!("{:<12}", control)).add_modifier(Modifier::BOLD),
+                    Span::raw(action),
+                ])
+            }),
+    );
+
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), inner_rect);
+}
+
+fn heading(text: &str) -> Line<'_> {
+    Line::from(
+        Span::raw(text)
+            .add_modifier(Modifier::BOLD)
+            .add_modifier(Modifier::UNDERLINED),
+    )
+}
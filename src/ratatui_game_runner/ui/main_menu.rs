@@ -0,0 +1,114 @@
+use crate::{
+    banner::BANNER_LINES,
+    ratatui_game_runner::{
+        app::{App, CurrentView, MainMenuItem},
+        tui::Frame,
+        ui::{controls, shared},
+    },
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Paragraph, Wrap},
+};
+
+pub(crate) fn render(app: &mut App, frame: &mut Frame) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(frame.size());
+
+    let banner_height: u16 = BANNER_LINES
+        .len()
+        .try_into()
+        .expect("banner height should never be greater than u16 length");
+    let menu_height: u16 = MainMenuItem::ALL
+        .len()
+        .try_into()
+        .expect("menu length should never be greater than u16 length");
+
+    let notice_height = if app.word_notice.is_some() { 4 } else { 0 };
+
+    let area = shared::centered_rect(
+        50,
+        banner_height + menu_height + notice_height + 4,
+        chunks[0],
+    );
+
+    let vertical_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(banner_height),
+            Constraint::Length(2),
+            Constraint::Length(menu_height),
+            Constraint::Length(notice_height),
+        ])
+        .split(area);
+
+    if app.settings.accessible {
+        frame.render_widget(
+            Paragraph::new(app.settings.locale.catalogue().hangman).alignment(Alignment::Center),
+            vertical_chunks[0],
+        );
+    } else {
+        render_banner(frame, vertical_chunks[0]);
+    }
+
+    render_menu(app, frame, vertical_chunks[2]);
+
+    if let Some(notice) = &app.word_notice {
+        let style = if app.settings.accessible {
+            Style::default()
+        } else {
+            Style::default().fg(Color::Red)
+        };
+
+        frame.render_widget(
+            Paragraph::new(format!("\n{}", notice))
+                .alignment(Alignment::Center)
+                .wrap(Wrap { trim: true })
+                .style(style),
+            vertical_chunks[3],
+        );
+    }
+
+    shared::render_controls(frame, chunks[1], &controls::for_view(app));
+}
+
+fn render_banner(frame: &mut Frame, area: Rect) {
+    frame.render_widget(
+        Paragraph::new(
+            BANNER_LINES
+                .iter()
+                .map(|&line| Line::from(line))
+                .collect::<Vec<Line>>(),
+        )
+        .alignment(Alignment::Center)
+        .style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ),
+        area,
+    );
+}
+
+fn render_menu(app: &mut App, frame: &mut Frame, area: Rect) {
+    let CurrentView::MainMenu(main_menu_state) = &app.current_view else {
+        unreachable!("the main menu is only shown when the current view is MainMenu");
+    };
+
+    let selected_index = MainMenuItem::ALL
+        .iter()
+        .position(|item| *item == main_menu_state.currently_selected)
+        .expect("selected item should be a main menu item");
+
+    app.menu_item_areas = shared::render_menu(
+        frame,
+        area,
+        &MainMenuItem::ALL.map(|item| item.label(app.settings.locale.catalogue())),
+        selected_index,
+        app.settings.accessible,
+    );
+}
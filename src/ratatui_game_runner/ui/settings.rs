@@ -0,0 +1,109 @@
+use crate::ratatui_game_runner::{
+    app::{App, CurrentView, SettingsItem},
+    tui::Frame,
+    ui::{controls, shared},
+};
+use hangman::words::Difficulty;
+use ratatui::{
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+pub(crate) fn render(app: &mut App, frame: &mut Frame) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(frame.size());
+
+    let CurrentView::Settings(settings_state) = &app.current_view else {
+        unreachable!("settings are only shown when the current view is Settings");
+    };
+
+    let text = app.settings.locale.catalogue();
+    let inner_rect = shared::render_block(
+        frame,
+        shared::centered_rect(50, SettingsItem::ALL.len() as u16 + 4, chunks[0]),
+        text.settings,
+    );
+
+    let label_width = SettingsItem::ALL
+        .iter()
+        .map(|item| item.label(text).chars().count() + 2)
+        .max()
+        .unwrap_or_default();
+    let categories = app.settings.categories(&app.words);
+    let value_width = [
+        text.on,
+        text.off,
+        text.any_category,
+        text.no_limit,
+        text.easy,
+        text.medium,
+        text.hard,
+        "999",
+    ]
+    .iter()
+    .copied()
+    .chain(categories.iter().map(String::as_str))
+    .map(|value| value.chars().count())
+    .max()
+    .unwrap_or_default();
+
+    let lines = SettingsItem::ALL
+        .iter()
+        .map(|item| {
+            let on_off = |value: bool| String::from(if value { text.on } else { text.off });
+            let limit = |value: Option<usize>| {
+                value.map_or(String::from(text.no_limit), |value| value.to_string())
+            };
+            let value = match item {
+                SettingsItem::Lives => app.settings.lives.to_string(),
+                SettingsItem::MinimumWordSize => app.settings.minimum_word_size.to_string(),
+                SettingsItem::MaximumWordSize => limit(app.settings.maximum_word_size),
+                SettingsItem::ExactWordSize => limit(app.exact_word_size()),
+                SettingsItem::UniqueLetters => limit(app.settings.unique_letters),
+                SettingsItem::ReducedMotion => on_off(app.settings.reduced_motion),
+                SettingsItem::AccessibleMode => on_off(app.settings.accessible),
+                SettingsItem::UndoAllowance => app.settings.undo_allowance.to_string(),
+                SettingsItem::UndoCost => app.settings.undo_cost.to_string(),
+                SettingsItem::ConfirmGuesses => on_off(app.settings.confirm_guesses),
+                SettingsItem::Category => app
+                    .settings
+                    .category
+                    .clone()
+                    .unwrap_or_else(|| String::from(text.any_category)),
+                SettingsItem::Difficulty => String::from(match app.settings.difficulty {
+                    Difficulty::Easy => text.easy,
+                    Difficulty::Medium => text.medium,
+                    Difficulty::Hard => text.hard,
+                }),
+            };
+
+            let is_selected = *item == settings_state.currently_selected;
+            let style = if is_selected && !app.settings.accessible {
+                Style::default().bg(Color::Cyan).fg(Color::Black)
+            } else {
+                Style::default()
+            };
+            let marker = if is_selected && app.settings.accessible {
+                "> "
+            } else {
+                "  "
+            };
+
+            Line::from(vec![
+                Span::styled(
+                    format!("{}{:<label_width$}", marker, item.label(text)),
+                    style,
+                ),
+                Span::styled(format!("< {:>value_width$} >", value), style),
+            ])
+        })
+        .collect::<Vec<Line>>();
+
+    frame.render_widget(Paragraph::new(lines), inner_rect);
+
+    shared::render_controls(frame, chunks[1], &controls::for_view(app));
+}
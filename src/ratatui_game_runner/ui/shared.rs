@@ -1,9 +1,9 @@
 use crate::ratatui_game_runner::tui::Frame;
 use ratatui::{
-    layout::{Constraint, Direction, Layout, Margin, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
     style::{Color, Style, Stylize},
-    text::Span,
-    widgets::{Block, BorderType, Borders},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
 };
 
 pub(crate) fn control_span<'a>(control: &'a str, action: &'a str) -> [Span<'a>; 2] {
@@ -19,6 +19,65 @@ pub(crate) fn render_block(frame: &mut Frame, area: Rect, title: &str) -> Rect {
     render_styled_block(frame, area, title, Style::default())
 }
 
+/// Renders one item per line, highlighting the selected one. Plain menus mark the selection with
+/// an arrow instead of a colour.
+pub(crate) fn render_menu(
+    frame: &mut Frame,
+    area: Rect,
+    items: &[&str],
+    selected_index: usize,
+    plain: bool,
+) -> Vec<Rect> {
+    let item_areas = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            items
+                .iter()
+                .map(|_| Constraint::Length(1))
+                .chain([Constraint::Min(0)])
+                .collect::<Vec<Constraint>>(),
+        )
+        .split(area);
+
+    items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let is_selected = index == selected_index;
+            let style = if is_selected && !plain {
+                Style::default().bg(Color::Cyan).fg(Color::Black)
+            } else {
+                Style::default()
+            };
+            let item = match (is_selected, plain) {
+                (true, true) => format!("> {}", item),
+                _ => item.to_string(),
+            };
+
+            frame.render_widget(
+                Paragraph::new(pad(item))
+                    .alignment(Alignment::Center)
+                    .style(style),
+                item_areas[index],
+            );
+
+            item_areas[index]
+        })
+        .collect()
+}
+
+pub(crate) fn render_controls(frame: &mut Frame, area: Rect, controls: &[(String, &str)]) {
+    let spans: Vec<Span> = controls
+        .iter()
+        .flat_map(|(control, action)| control_span(control, action))
+        .collect();
+
+    frame.render_widget(
+        Paragraph::new(Line::from(spans)).alignment(Alignment::Center),
+        area,
+    );
+}
+
 pub(crate) fn render_styled_block(
     frame: &mut Frame,
     area: Rect,
@@ -43,18 +102,18 @@ pub(crate) fn centered_rect(width: u16, height: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length((r.height - height) / 2),
+            Constraint::Length(r.height.saturating_sub(height) / 2),
             Constraint::Length(height),
-            Constraint::Length((r.height - height) / 2),
+            Constraint::Length(r.height.saturating_sub(height) / 2),
         ])
         .split(r);
 
     Layout::default()
         .direction(Direction::Horizontal)
         .constraints([
-            Constraint::Length((r.width - width) / 2),
+            Constraint::Length(r.width.saturating_sub(width) / 2),
             Constraint::Length(width),
-            Constraint::Length((r.width - width) / 2),
+            Constraint::Length(r.width.saturating_sub(width) / 2),
         ])
         .split(popup_layout[1])[1]
 }
@@ -0,0 +1,46 @@
+use crate::{
+    i18n::fill,
+    ratatui_game_runner::{
+        app::App,
+        tui::Frame,
+        ui::{controls, shared},
+    },
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    text::Line,
+    widgets::Paragraph,
+};
+
+pub(crate) fn render(app: &mut App, frame: &mut Frame) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(frame.size());
+
+    let text = app.settings.locale.catalogue();
+    let inner_rect = shared::render_block(
+        frame,
+        shared::centered_rect(40, 8, chunks[0]),
+        text.statistics,
+    );
+
+    let win_rate = if app.games_played == 0 {
+        0
+    } else {
+        u32::from(app.games_won) * 100 / u32::from(app.games_played)
+    };
+
+    frame.render_widget(
+        Paragraph::new(vec![
+            Line::from(fill(text.games_played, &[("count", &app.games_played)])),
+            Line::from(fill(text.games_won, &[("count", &app.games_won)])),
+            Line::from(fill(text.games_lost, &[("count", &app.games_lost)])),
+            Line::from(fill(text.win_rate, &[("rate", &win_rate)])),
+        ])
+        .alignment(Alignment::Center),
+        inner_rect,
+    );
+
+    shared::render_controls(frame, chunks[1], &controls::for_view(app));
+}
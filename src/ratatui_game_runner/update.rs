@@ -1,68 +1,304 @@
 use crate::ratatui_game_runner::{
-    app::{App, CurrentView, GameCompleteState, PlayAgain},
+    app::{
+        App, CurrentView, GameCompleteState, GameMode, GameModesState, MainMenuItem, MainMenuState,
+        PlayAgain, SettingsItem, SettingsState,
+    },
     event_handler::Event,
+    key_bindings::Action,
 };
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 
 pub(crate) fn update(app: &mut App, event: Event) {
     match event {
-        Event::Tick => {}
+        Event::Tick => app.tick(),
         Event::Key(key) => update_key(app, key),
-        Event::Mouse(_) => {}
+        Event::Mouse(mouse) => update_mouse(app, mouse),
         Event::Resize(_, _) => {}
     }
 }
 
 fn update_key(app: &mut App, key_event: KeyEvent) {
-    let handled = handle_quit_keys(app, key_event);
+    app.animations.skip();
 
-    if handled {
+    let action = app.key_bindings.action(key_event);
+
+    if action == Some(Action::Exit) {
+        app.quit();
+        return;
+    }
+
+    if app.show_help {
+        if let Some(Action::Help | Action::Back | Action::Select) = action {
+            app.toggle_help();
+        }
+
+        return;
+    }
+
+    if action == Some(Action::Help) {
+        app.toggle_help();
         return;
     }
 
+    // Keys bound to menu navigation are free to be guessed during a game.
+    let action = action.filter(|action| {
+        app.current_view != CurrentView::GameInProgress || action.is_available_during_game()
+    });
+
+    let Some(action) = action else {
+        // Unbound Ctrl and Alt combinations are most likely slips rather than guesses.
+        let is_plain_key = !key_event
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT);
+
+        if let (CurrentView::GameInProgress, KeyCode::Char(char), true) =
+            (&app.current_view, key_event.code, is_plain_key)
+        {
+            if app.settings.confirm_guesses {
+                app.select_letter(char);
+            } else {
+                app.make_guess(char.to_string().as_str());
+            }
+        }
+
+        return;
+    };
+
     match &app.current_view {
-        CurrentView::GameInProgress => {
-            match key_event.code {
-                KeyCode::Char(char) => app.make_guess(char.to_string().as_str()),
-                _ => {}
-            };
+        CurrentView::MainMenu(main_menu_state) => match action {
+            Action::Up | Action::Down => {
+                app.current_view = CurrentView::MainMenu(MainMenuState {
+                    currently_selected: cycle(
+                        &MainMenuItem::ALL,
+                        main_menu_state.currently_selected,
+                        action == Action::Down,
+                    ),
+                })
+            }
+            Action::Select => app.select_main_menu_item(main_menu_state.currently_selected),
+            Action::Back => app.quit(),
+            _ => {}
+        },
+        CurrentView::GameModes(game_modes_state) => match action {
+            Action::Up | Action::Down => {
+                app.current_view = CurrentView::GameModes(GameModesState {
+                    currently_selected: cycle(
+                        &GameMode::ALL,
+                        game_modes_state.currently_selected,
+                        action == Action::Down,
+                    ),
+                })
+            }
+            Action::Select => app.select_game_mode(game_modes_state.currently_selected),
+            Action::Back => app.show_main_menu(),
+            _ => {}
+        },
+        CurrentView::Settings(settings_state) => match action {
+            Action::Up | Action::Down => {
+                app.current_view = CurrentView::Settings(SettingsState {
+                    currently_selected: cycle(
+                        &SettingsItem::ALL,
+                        settings_state.currently_selected,
+                        action == Action::Down,
+                    ),
+                })
+            }
+            Action::Left | Action::Right => {
+                app.adjust_setting(settings_state.currently_selected, action == Action::Right)
+            }
+            Action::Back | Action::Select => app.show_main_menu(),
+            _ => {}
+        },
+        CurrentView::Statistics => {
+            if let Action::Back | Action::Select = action {
+                app.show_main_menu();
+            }
         }
-        CurrentView::GameComplete(game_complete_state) => match key_event.code {
-            KeyCode::Left | KeyCode::Right => {
+        CurrentView::GameInProgress => match action {
+            Action::Back if app.selected_letter.is_some() => app.selected_letter = None,
+            Action::Back => app.show_main_menu(),
+            Action::Select => app.confirm_guess(),
+            Action::Undo => app.undo(),
+            Action::Redo => app.redo(),
+            _ => {}
+        },
+        CurrentView::GameComplete(game_complete_state) => match action {
+            Action::Left | Action::Right => {
                 app.current_view = CurrentView::GameComplete(GameCompleteState {
                     currently_selected: match game_complete_state.currently_selected {
                         PlayAgain::Yes => PlayAgain::No,
                         PlayAgain::No => PlayAgain::Yes,
                     },
-                    ..*game_complete_state
                 })
             }
-            KeyCode::Enter => {
+            Action::Select => {
                 if game_complete_state.currently_selected == PlayAgain::No {
-                    app.quit();
+                    app.show_main_menu();
                 } else {
                     app.start_new_game();
                 }
             }
+            Action::Copy if app.is_daily_game => app.copy_daily_result(),
+            Action::Back => app.show_main_menu(),
+            _ => {}
+        },
+        CurrentView::DailyPlayed => match action {
+            Action::Copy => app.copy_daily_result(),
+            Action::Back | Action::Select => app.show_main_menu(),
             _ => {}
         },
     }
 }
 
-fn handle_quit_keys(app: &mut App, key_event: KeyEvent) -> bool {
-    match key_event.code {
-        KeyCode::Esc => app.quit(),
-        KeyCode::Char('c') | KeyCode::Char('C') => {
-            if key_event.modifiers == KeyModifiers::CONTROL {
-                app.quit()
-            } else {
-                return false;
+fn update_mouse(app: &mut App, mouse_event: MouseEvent) {
+    if app.show_help {
+        return;
+    }
+
+    let hovered_index = app.menu_item_areas.iter().position(|area| {
+        (area.left()..area.right()).contains(&mouse_event.column)
+            && (area.top()..area.bottom()).contains(&mouse_event.row)
+    });
+
+    match &app.current_view {
+        CurrentView::MainMenu(main_menu_state) => {
+            let selected = match mouse_event.kind {
+                MouseEventKind::ScrollUp | MouseEventKind::ScrollDown => cycle(
+                    &MainMenuItem::ALL,
+                    main_menu_state.currently_selected,
+                    mouse_event.kind == MouseEventKind::ScrollDown,
+                ),
+                _ => match hovered_index.and_then(|index| MainMenuItem::ALL.get(index)) {
+                    Some(item) => *item,
+                    None => return,
+                },
+            };
+
+            app.current_view = CurrentView::MainMenu(MainMenuState {
+                currently_selected: selected,
+            });
+
+            if mouse_event.kind == MouseEventKind::Down(MouseButton::Left) {
+                app.select_main_menu_item(selected);
             }
         }
-        _ => {
-            return false;
+        CurrentView::GameModes(game_modes_state) => {
+            let selected = match mouse_event.kind {
+                MouseEventKind::ScrollUp | MouseEventKind::ScrollDown => cycle(
+                    &GameMode::ALL,
+                    game_modes_state.currently_selected,
+                    mouse_event.kind == MouseEventKind::ScrollDown,
+                ),
+                _ => match hovered_index.and_then(|index| GameMode::ALL.get(index)) {
+                    Some(item) => *item,
+                    None => return,
+                },
+            };
+
+            app.current_view = CurrentView::GameModes(GameModesState {
+                currently_selected: selected,
+            });
+
+            if mouse_event.kind == MouseEventKind::Down(MouseButton::Left) {
+                app.select_game_mode(selected);
+            }
         }
+        _ => {}
+    }
+}
+
+fn cycle<T: Copy + PartialEq>(items: &[T], current: T, forwards: bool) -> T {
+    let index = items
+        .iter()
+        .position(|item| *item == current)
+        .expect("current item should be one of the items");
+
+    if forwards {
+        items[(index + 1) % items.len()]
+    } else {
+        items[(index + items.len() - 1) % items.len()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        config::Config, daily_records::DailyRecords,
+        ratatui_game_runner::key_bindings::KeyBindings, settings::Settings,
+        word_history::WordHistory,
+    };
+    use hangman::words::WordList;
+
+    fn press(app: &mut App, code: KeyCode) {
+        update(app, Event::Key(KeyEvent::new(code, KeyModifiers::NONE)));
     }
 
-    return true;
+    fn unguessed_letter(app: &App) -> char {
+        let letters = app.current_game.letters();
+
+        ('a'..='z')
+            .find(|letter| !letters.contains(&letter.to_ascii_uppercase().to_string()))
+            .expect("no word uses every letter")
+    }
+
+    #[test]
+    fn in_confirm_mode_a_letter_is_only_guessed_once_it_is_confirmed() {
+        let settings = Settings {
+            confirm_guesses: true,
+            ..Settings::default()
+        };
+        let mut app = App::new(
+            settings,
+            KeyBindings::default(),
+            WordList::built_in().clone(),
+            DailyRecords::default(),
+            WordHistory::default(),
+        )
+        .unwrap();
+        app.start_new_game();
+
+        let letter = unguessed_letter(&app);
+
+        press(&mut app, KeyCode::Char(letter));
+        assert_eq!(
+            app.selected_letter,
+            Some(letter.to_ascii_uppercase().to_string())
+        );
+        assert!(app.current_game.guesses().is_empty());
+
+        press(&mut app, KeyCode::Esc);
+        assert_eq!(app.selected_letter, None);
+        assert!(app.current_view == CurrentView::GameInProgress);
+
+        press(&mut app, KeyCode::Char(letter));
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.selected_letter, None);
+        assert_eq!(
+            app.current_game.guess_letters(),
+            vec![letter.to_ascii_uppercase().to_string()]
+        );
+    }
+
+    #[test]
+    fn keys_bound_to_menu_navigation_are_guesses_during_a_game() {
+        let config = Config::parse("[keys]\nup = Up, k\n").unwrap();
+        let mut app = App::new(
+            Settings::default(),
+            KeyBindings::from_config(&config).unwrap(),
+            WordList::built_in().clone(),
+            DailyRecords::default(),
+            WordHistory::default(),
+        )
+        .unwrap();
+        app.start_new_game();
+
+        press(&mut app, KeyCode::Char('k'));
+        update(
+            &mut app,
+            Event::Key(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL)),
+        );
+
+        assert_eq!(app.current_game.guess_letters(), vec![String::from("K")]);
+    }
 }
@@ -0,0 +1,24 @@
+use crate::settings::Settings;
+
+pub(crate) fn rules(settings: &Settings, lives: usize) -> Vec<String> {
+    let locale = settings.locale;
+    let text = locale.catalogue();
+
+    let mut rules = vec![
+        locale.plural(&text.rule_lives, lives),
+        String::from(text.rule_blanked_out_word),
+        String::from(text.rule_incorrect_guess),
+        String::from(text.rule_no_lives),
+        String::from(text.rule_all_letters),
+    ];
+
+    if settings.undo_allowance > 0 {
+        rules.push(locale.plural(&text.rule_undos, settings.undo_allowance));
+
+        if settings.undo_cost > 0 {
+            rules.push(locale.plural(&text.rule_undo_cost, settings.undo_cost));
+        }
+    }
+
+    rules
+}
@@ -0,0 +1,521 @@
+use crate::{
+    config::{Config, ConfigError},
+    i18n::{fill, Locale},
+    messages,
+};
+use hangman::{
+    daily::Date,
+    words::{Difficulty, PickWordError, RecentWords, WordEntry, WordFilter, WordList},
+    Game, GameBuilder, Rules,
+};
+use std::str::FromStr;
+
+const SETTINGS_SECTION: &str = "settings";
+pub(crate) const ANY_CATEGORY: &str = "any";
+/// Stands for no limit in the optional whole number settings.
+pub(crate) const NO_LIMIT: &str = "any";
+/// Settings only read when the game starts, such as the ones that screen the word list.
+#[cfg(feature = "stdio_game_runner")]
+const STARTUP_SETTINGS: [&str; 1] = ["family_friendly"];
+/// The word of the day ignores the word length and category settings, and the word packs and
+/// screens loaded from the packs directory, so that everyone playing with the same salt gets the
+/// same word.
+const DAILY_MINIMUM_WORD_SIZE: usize = 4;
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Settings {
+    pub(crate) lives: usize,
+    pub(crate) minimum_word_size: usize,
+    /// The longest word that can be picked, or `None` for any length.
+    pub(crate) maximum_word_size: Option<usize>,
+    /// The number of different letters words must have, or `None` for any number.
+    pub(crate) unique_letters: Option<usize>,
+    pub(crate) reduced_motion: bool,
+    pub(crate) accessible: bool,
+    pub(crate) undo_allowance: usize,
+    pub(crate) undo_cost: usize,
+    pub(crate) confirm_guesses: bool,
+    /// The category words are picked from, or `None` for any category.
+    pub(crate) category: Option<String>,
+    /// Mixed into the date to pick the word of the day, so a team can share their own puzzle.
+    pub(crate) daily_salt: String,
+    /// The percentage of the words that can be picked that must be played before a word can come
+    /// up again.
+    pub(crate) repeat_after: usize,
+    /// Whether offensive words are kept out of the game.
+    pub(crate) family_friendly: bool,
+    /// Whether common words (easy) or rare words (hard) are picked more often, going by the
+    /// frequencies in the word packs.
+    pub(crate) difficulty: Difficulty,
+    pub(crate) locale: Locale,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            lives: 10,
+            minimum_word_size: 4,
+            maximum_word_size: None,
+            unique_letters: None,
+            reduced_motion: false,
+            accessible: false,
+            undo_allowance: 3,
+            undo_cost: 0,
+            confirm_guesses: false,
+            category: None,
+            daily_salt: String::from("hangman"),
+            repeat_after: 50,
+            family_friendly: true,
+            difficulty: Difficulty::Medium,
+            locale: Locale::default(),
+        }
+    }
+}
+
+impl Settings {
+    pub(crate) fn from_config(config: &Config) -> Result<Self, ConfigError> {
+        let mut settings = Settings::default();
+
+        for entry in config.section(SETTINGS_SECTION) {
+            settings
+                .set(&entry.key, &entry.value)
+                .map_err(|message| ConfigError::Value {
+                    line: entry.line,
+                    message,
+                })?;
+        }
+
+        Ok(settings)
+    }
+
+    pub(crate) fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "lives" => self.lives = parse_positive(name, value)?,
+            "minimum_word_size" => self.minimum_word_size = parse_positive(name, value)?,
+            "maximum_word_size" => self.maximum_word_size = parse_optional_positive(name, value)?,
+            "word_size" => {
+                let word_size = parse_positive(name, value)?;
+
+                self.minimum_word_size = word_size;
+                self.maximum_word_size = Some(word_size);
+            }
+            "unique_letters" => self.unique_letters = parse_optional_positive(name, value)?,
+            "reduced_motion" => self.reduced_motion = parse_bool(name, value)?,
+            "accessible" => self.accessible = parse_bool(name, value)?,
+            "undo_allowance" => self.undo_allowance = parse_whole(name, value)?,
+            "undo_cost" => self.undo_cost = parse_whole(name, value)?,
+            "confirm_guesses" => self.confirm_guesses = parse_bool(name, value)?,
+            "category" => {
+                self.category = match value.to_lowercase() {
+                    category if category == ANY_CATEGORY => None,
+                    category => Some(category),
+                }
+            }
+            "daily_salt" => self.daily_salt = value.to_string(),
+            "repeat_after" => self.repeat_after = parse_percentage(name, value)?,
+            "family_friendly" => self.family_friendly = parse_bool(name, value)?,
+            "difficulty" => {
+                self.difficulty = Difficulty::from_name(value).ok_or_else(|| {
+                    format!(
+                        "\"{}\" must be easy, medium or hard but was \"{}\"",
+                        name, value
+                    )
+                })?
+            }
+            _ => return Err(format!("unknown setting \"{}\"", name)),
+        }
+
+        Ok(())
+    }
+
+    /// Changes a setting during a game session, refusing the ones that would only take effect
+    /// once the game is restarted.
+    #[cfg(feature = "stdio_game_runner")]
+    pub(crate) fn set_in_session(&mut self, name: &str, value: &str) -> Result<(), String> {
+        if STARTUP_SETTINGS.contains(&name) {
+            return Err(format!(
+                "\"{}\" takes effect on restart, so change it in the config file",
+                name
+            ));
+        }
+
+        self.set(name, value)
+    }
+
+    pub(crate) fn rules(&self) -> Rules {
+        Rules::new()
+            .undo_allowance(self.undo_allowance)
+            .undo_cost(self.undo_cost)
+    }
+
+    /// The categories that have words in the language of the locale.
+    pub(crate) fn categories(&self, words: &WordList) -> Vec<String> {
+        words.categories(Some(self.locale.code()))
+    }
+
+    /// Checks that the chosen category has words in the language of the locale, and that some of
+    /// them are the chosen length. Errors are in the language of the locale, and when no word
+    /// matches they suggest what to change.
+    pub(crate) fn validate_words(&self, words: &WordList) -> Result<(), String> {
+        let text = self.locale.catalogue();
+        let categories = self.categories(words);
+
+        if let Some(category) = self
+            .category
+            .as_ref()
+            .filter(|category| !categories.contains(category))
+        {
+            return Err(fill(
+                text.unknown_category,
+                &[
+                    ("category", category),
+                    (
+                        "categories",
+                        &format!("{}, {}", ANY_CATEGORY, categories.join(", ")),
+                    ),
+                ],
+            ));
+        }
+
+        if let Some(maximum_word_size) = self
+            .maximum_word_size
+            .filter(|maximum_word_size| *maximum_word_size < self.minimum_word_size)
+        {
+            return Err(fill(
+                text.word_sizes_crossed,
+                &[
+                    ("minimum", &self.minimum_word_size),
+                    ("maximum", &maximum_word_size),
+                ],
+            ));
+        }
+
+        words
+            .random_entry(&self.word_filter())
+            .map(|_| ())
+            .map_err(|error| messages::pick_word_error(self.locale, &error))
+    }
+
+    pub(crate) fn word_filter(&self) -> WordFilter {
+        let mut filter = WordFilter::new()
+            .language(self.locale.code())
+            .minimum_length(self.minimum_word_size);
+
+        if let Some(maximum_word_size) = self.maximum_word_size {
+            filter = filter.maximum_length(maximum_word_size);
+        }
+
+        if let Some(unique_letters) = self.unique_letters {
+            filter = filter.unique_letters(unique_letters);
+        }
+
+        match &self.category {
+            Some(category) => filter.category(category),
+            None => filter,
+        }
+    }
+
+    /// Starts a game with a random word using these settings, with the lives of the game mode,
+    /// leaving out recent words.
+    pub(crate) fn new_game(
+        &self,
+        words: &WordList,
+        recent: &RecentWords,
+        lives: usize,
+    ) -> Result<Game, PickWordError> {
+        let entry = words.random_fresh_entry(
+            &self.word_filter(),
+            recent,
+            self.repeat_after as f64 / 100.0,
+            self.difficulty,
+        )?;
+
+        Ok(self.build_game(entry.clone(), lives))
+    }
+
+    /// Starts a game with the word of the day in the language of the locale, picked from the
+    /// built-in words.
+    pub(crate) fn daily_game(&self, date: Date) -> Result<Game, PickWordError> {
+        let filter = WordFilter::new()
+            .language(self.locale.code())
+            .minimum_length(DAILY_MINIMUM_WORD_SIZE);
+        let entry = WordList::built_in()
+            .daily_entry(&filter, date, &self.daily_salt)?
+            .clone();
+
+        Ok(self.build_game(entry, self.lives))
+    }
+
+    fn build_game(&self, entry: WordEntry, lives: usize) -> Game {
+        GameBuilder::new()
+            .entry(entry)
+            .lives(lives)
+            .rules(self.rules())
+            .build()
+            .expect("games should always be started with at least one life")
+    }
+}
+
+fn parse_positive(name: &str, value: &str) -> Result<usize, String> {
+    match usize::from_str(value) {
+        Ok(value) if value > 0 => Ok(value),
+        _ => Err(format!(
+            "\"{}\" must be a whole number greater than 0 but was \"{}\"",
+            name, value
+        )),
+    }
+}
+
+/// Reads a whole number greater than 0, or `any` for no limit.
+fn parse_optional_positive(name: &str, value: &str) -> Result<Option<usize>, String> {
+    if value.eq_ignore_ascii_case(NO_LIMIT) {
+        return Ok(None);
+    }
+
+    parse_positive(name, value).map(Some).map_err(|_| {
+        format!(
+            "\"{}\" must be a whole number greater than 0 or \"{}\" but was \"{}\"",
+            name, NO_LIMIT, value
+        )
+    })
+}
+
+fn parse_whole(name: &str, value: &str) -> Result<usize, String> {
+    usize::from_str(value)
+        .map_err(|_| format!("\"{}\" must be a whole number but was \"{}\"", name, value))
+}
+
+fn parse_percentage(name: &str, value: &str) -> Result<usize, String> {
+    match usize::from_str(value.trim_end_matches('%')) {
+        Ok(value) if value <= 100 => Ok(value),
+        _ => Err(format!(
+            "\"{}\" must be a percentage from 0 to 100 but was \"{}\"",
+            name, value
+        )),
+    }
+}
+
+fn parse_bool(name: &str, value: &str) -> Result<bool, String> {
+    match value.to_lowercase().as_str() {
+        "true" | "yes" | "on" => Ok(true),
+        "false" | "no" | "off" => Ok(false),
+        _ => Err(format!(
+            "\"{}\" must be true or false but was \"{}\"",
+            name, value
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn settings_missing_from_the_config_use_the_defaults() {
+        let config = Config::parse("[settings]\nlives = 6\n").unwrap();
+
+        assert_eq!(
+            Settings::from_config(&config).unwrap(),
+            Settings {
+                lives: 6,
+                ..Settings::default()
+            }
+        );
+    }
+
+    #[test]
+    fn invalid_setting_values_return_an_error_with_the_line_number() {
+        let config = Config::parse("[settings]\nlives = 0\nreduced_motion = maybe\n").unwrap();
+
+        assert!(matches!(
+            Settings::from_config(&config),
+            Err(ConfigError::Value { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn new_games_pick_a_word_from_the_chosen_category_in_the_language_of_the_locale() {
+        let words = WordList::parse(
+            "@language en\n@category animals\nhorse\n@category food\ncheese\n\
+             @language fr\n@category animaux\ncheval\n",
+        )
+        .unwrap();
+        let mut settings = Settings::default();
+
+        settings.set("category", "Food").unwrap();
+        assert_eq!(settings.validate_words(&words), Ok(()));
+        assert_eq!(
+            settings
+                .new_game(&words, &RecentWords::new(), 5)
+                .unwrap()
+                .letters()
+                .concat(),
+            "CHEESE"
+        );
+
+        settings.locale = Locale::French;
+        assert_eq!(
+            settings.validate_words(&words),
+            Err(String::from(
+                "catégorie « food » inconnue, choisissez parmi : any, animaux"
+            ))
+        );
+
+        settings.set("category", "any").unwrap();
+        assert_eq!(
+            settings
+                .new_game(&words, &RecentWords::new(), 5)
+                .unwrap()
+                .letters()
+                .concat(),
+            "CHEVAL"
+        );
+    }
+
+    #[test]
+    fn words_can_be_limited_to_a_maximum_or_exact_length_and_number_of_different_letters() {
+        let words = WordList::parse(
+            "@language en\n@category animals\ncat\nllama\nhorse\nkangaroo\n@category food\nbanana\n",
+        )
+        .unwrap();
+        let mut settings = Settings::default();
+
+        settings.set("maximum_word_size", "5").unwrap();
+        settings.set("unique_letters", "3").unwrap();
+        assert_eq!(settings.validate_words(&words), Ok(()));
+        assert_eq!(
+            settings
+                .new_game(&words, &RecentWords::new(), 5)
+                .unwrap()
+                .letters()
+                .concat(),
+            "LLAMA"
+        );
+
+        settings.set("word_size", "6").unwrap();
+        settings.set("category", "animals").unwrap();
+        assert_eq!(
+            settings.validate_words(&words),
+            Err(String::from(
+                "No word could be picked: there are no words in \"en\" in the \"animals\" category \
+                 with 6 letters and 3 different letters. Try a smaller minimum word size, a \
+                 larger maximum, another category or more word packs."
+            ))
+        );
+
+        settings.set("maximum_word_size", "5").unwrap();
+        assert_eq!(
+            settings.validate_words(&words),
+            Err(String::from(
+                "\"minimum_word_size\" (6) can't be more than \"maximum_word_size\" (5)"
+            ))
+        );
+
+        settings.set("maximum_word_size", "any").unwrap();
+        settings.set("unique_letters", "any").unwrap();
+        assert_eq!(settings.validate_words(&words), Ok(()));
+        assert_eq!(
+            settings.set("maximum_word_size", "0"),
+            Err(String::from(
+                "\"maximum_word_size\" must be a whole number greater than 0 or \"any\" but was \"0\""
+            ))
+        );
+    }
+
+    #[test]
+    fn why_no_word_could_be_picked_is_in_the_language_of_the_locale() {
+        let words = WordList::parse("@language fr\n@category animaux\nchat\ncheval\n").unwrap();
+        let settings = Settings {
+            maximum_word_size: Some(5),
+            unique_letters: Some(1),
+            category: Some(String::from("animaux")),
+            locale: Locale::French,
+            ..Settings::default()
+        };
+
+        assert_eq!(
+            settings.validate_words(&words),
+            Err(String::from(
+                "Aucun mot n'a pu être choisi : il n'y a pas de mots en « fr » de la catégorie \
+                 « animaux » de 4 à 5 lettres et 1 lettre différente. Essayez une taille minimale \
+                 plus petite, une taille maximale plus grande, une autre catégorie ou d'autres \
+                 paquets de mots."
+            ))
+        );
+    }
+
+    #[test]
+    fn the_daily_game_ignores_the_category_and_word_size_and_depends_on_the_salt() {
+        let mut settings = Settings::default();
+        let date = Date::new(2024, 2, 29).unwrap();
+        let daily_words = |settings: &Settings| {
+            (0..10)
+                .map(|days| {
+                    settings
+                        .daily_game(Date::from_days_since_epoch(19_782 + days))
+                        .unwrap()
+                        .letters()
+                        .concat()
+                })
+                .collect::<Vec<String>>()
+        };
+        let team_words = daily_words(&settings);
+
+        settings.set("category", "food").unwrap();
+        settings.set("word_size", "20").unwrap();
+        assert_eq!(daily_words(&settings), team_words);
+        assert_eq!(
+            settings.daily_game(date).unwrap().letters().concat(),
+            team_words[0]
+        );
+
+        settings.set("daily_salt", "another team").unwrap();
+        assert_ne!(daily_words(&settings), team_words);
+    }
+
+    #[test]
+    fn repeat_after_is_a_percentage_of_the_words_that_can_be_picked() {
+        let words = WordList::parse("@language en\n@category animals\nhorse\nsheep\n").unwrap();
+        let mut settings = Settings::default();
+        let mut recent = RecentWords::new();
+
+        settings.set("repeat_after", "100%").unwrap();
+        recent.record("en", "horse");
+        assert_eq!(
+            settings
+                .new_game(&words, &recent, 5)
+                .unwrap()
+                .letters()
+                .concat(),
+            "SHEEP"
+        );
+
+        assert_eq!(
+            settings.set("repeat_after", "150"),
+            Err(String::from(
+                "\"repeat_after\" must be a percentage from 0 to 100 but was \"150\""
+            ))
+        );
+    }
+
+    #[test]
+    fn the_difficulty_is_read_by_name_ignoring_case() {
+        let mut settings = Settings::default();
+
+        settings.set("difficulty", "Hard").unwrap();
+        assert_eq!(settings.difficulty, Difficulty::Hard);
+        assert_eq!(
+            settings.set("difficulty", "tricky"),
+            Err(String::from(
+                "\"difficulty\" must be easy, medium or hard but was \"tricky\""
+            ))
+        );
+    }
+
+    #[test]
+    fn unknown_settings_return_an_error() {
+        let config = Config::parse("[settings]\ncolour = blue\n").unwrap();
+
+        assert!(Settings::from_config(&config).is_err());
+    }
+}
@@ -1,104 +1,594 @@
-use crate::game::{
-    CompleteGameStatus, Game, GameStatus, GuessStatus, MakeGuessError, MakeGuessSuccess,
+use crate::{
+    accessibility,
+    banner::BANNER_LINES,
+    daily_records::{DailyRecord, DailyRecords},
+    i18n::{fill, Catalogue, Locale},
+    messages::{self, Tone},
+    rules::rules,
+    settings::{Settings, ANY_CATEGORY, NO_LIMIT},
+    word_history::WordHistory,
 };
 use colored::Colorize;
-use std::io::{stdin, Error};
+use command::{Command, COMMANDS};
+use hangman::{
+    daily::Date,
+    words::{PickWordError, WordList},
+    CompleteGameStatus, Game, GameStatus, GuessStatus, HintError, MakeGuessError, MakeGuessResult,
+    SolveResult,
+};
+use line_editor::LineEditor;
+use std::io::{self, stdin, stdout, BufRead, ErrorKind, IsTerminal, Write};
+
+mod command;
+mod line_editor;
+
+pub(crate) struct StdIOGameRunner<R: BufRead, W: Write> {
+    input: R,
+    output: W,
+    settings: Settings,
+    words: WordList,
+    summary: SessionSummary,
+}
+
+enum RoundResult {
+    Continue,
+    NewGame,
+    Quit,
+}
+
+#[derive(Debug, PartialEq)]
+enum GameResult {
+    Complete(CompleteGameStatus),
+    NewGame,
+    Quit,
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct SessionSummary {
+    games_won: usize,
+    games_lost: usize,
+}
+
+impl StdIOGameRunner<Box<dyn BufRead>, io::Stdout> {
+    pub(crate) fn run(
+        settings: Settings,
+        words: WordList,
+        mut daily_records: DailyRecords,
+        mut word_history: WordHistory,
+        daily: bool,
+    ) {
+        // Only interactive players need line editing, so piped input is read as it is.
+        let input: Box<dyn BufRead> = match stdin().is_terminal() {
+            true => match LineEditor::new() {
+                Ok(line_editor) => Box::new(line_editor),
+                Err(_) => Box::new(stdin().lock()),
+            },
+            false => Box::new(stdin().lock()),
+        };
+
+        let mut runner = StdIOGameRunner::new(input, stdout(), settings, words);
+
+        if daily {
+            runner.run_daily(&mut daily_records, Date::today())
+        } else {
+            runner.run_session(|settings, words| {
+                let game = settings.new_game(words, word_history.recent(), settings.lives)?;
+
+                // Failing to save only means the word may come up again sooner.
+                let _ = word_history.record(&game);
+
+                Ok(game)
+            })
+        }
+        .expect("failed to write to stdout");
+    }
+}
 
-pub(crate) struct StdIOGameRunner;
+impl<R: BufRead, W: Write> StdIOGameRunner<R, W> {
+    pub(crate) fn new(input: R, output: W, settings: Settings, words: WordList) -> Self {
+        StdIOGameRunner {
+            input,
+            output,
+            settings,
+            words,
+            summary: SessionSummary::default(),
+        }
+    }
+
+    fn locale(&self) -> Locale {
+        self.settings.locale
+    }
+
+    fn text(&self) -> &'static Catalogue {
+        self.settings.locale.catalogue()
+    }
 
-impl StdIOGameRunner {
-    pub(crate) fn run(lives: usize, minimum_word_size: usize) {
-        Self::print_intro();
+    fn run_session(
+        &mut self,
+        mut new_game: impl FnMut(&Settings, &WordList) -> Result<Game, PickWordError>,
+    ) -> io::Result<SessionSummary> {
+        self.print_intro()?;
 
         loop {
-            let mut game = Game::new(lives, minimum_word_size);
+            let mut game = match new_game(&self.settings, &self.words) {
+                Ok(game) => game,
+                Err(error) => {
+                    self.output_no_words(&error)?;
+                    break;
+                }
+            };
 
-            while game.status() == GameStatus::InProgress {
-                Self::play_guess_round(&mut game);
+            match self.play_game(&mut game, self.settings.lives)? {
+                GameResult::Complete(CompleteGameStatus::Won) => self.summary.games_won += 1,
+                GameResult::Complete(CompleteGameStatus::Lost) => self.summary.games_lost += 1,
+                GameResult::NewGame => continue,
+                GameResult::Quit => break,
             }
 
-            match game.status() {
-                GameStatus::InProgress => {
-                    unreachable!("The loop above only ends when the game is complete.")
-                }
-                GameStatus::Complete(complete_game_status) => {
-                    Self::handle_complete_game(&game, &complete_game_status)
-                }
+            if !self.prompt_for_new_game()? {
+                break;
+            }
+        }
+
+        self.print_session_summary()?;
+
+        Ok(std::mem::take(&mut self.summary))
+    }
+
+    /// Plays the word of the day, or shows how it went if it has already been played. Leaving the
+    /// game before it ends counts as a loss.
+    fn run_daily(&mut self, records: &mut DailyRecords, date: Date) -> io::Result<SessionSummary> {
+        let text = self.text();
+
+        self.print_intro()?;
+
+        if let Some(record) = records.get(date).cloned() {
+            self.output_message(Tone::Negative, text.daily_already_played)?;
+
+            return self
+                .output_daily_share(&record)
+                .map(|_| SessionSummary::default());
+        }
+
+        let lives = self.settings.lives;
+        let mut game = match self.settings.daily_game(date) {
+            Ok(game) => game,
+            Err(error) => {
+                return self
+                    .output_no_words(&error)
+                    .map(|_| SessionSummary::default())
             }
+        };
 
-            Self::prompt_for_new_game();
+        match self.play_game(&mut game, lives)? {
+            GameResult::Complete(CompleteGameStatus::Won) => self.summary.games_won += 1,
+            GameResult::Complete(CompleteGameStatus::Lost) => self.summary.games_lost += 1,
+            GameResult::NewGame | GameResult::Quit => {}
         }
+
+        let record = DailyRecord::from_game(date, &game, lives);
+
+        if let Err(error) = records.record(record.clone()) {
+            self.output_message(
+                Tone::Negative,
+                &fill(text.daily_not_saved, &[("error", &error)]),
+            )?;
+        }
+
+        self.output_daily_share(&record)?;
+        self.print_session_summary()?;
+
+        Ok(std::mem::take(&mut self.summary))
     }
 
-    fn print_intro() {
-        println!("Welcome to");
-        println!();
+    fn play_game(&mut self, game: &mut Game, lives: usize) -> io::Result<GameResult> {
+        while game.status() == GameStatus::InProgress {
+            match self.play_guess_round(game, lives)? {
+                RoundResult::Continue => {}
+                RoundResult::NewGame => return Ok(GameResult::NewGame),
+                RoundResult::Quit => return Ok(GameResult::Quit),
+            }
+        }
 
-        let lines = [
-            "===============================================",
-            " _   _                                         ",
-            "| | | | __ _ _ __   __ _ _ __ ___   __ _ _ __  ",
-            "| |_| |/ _` | '_ \\ / _` | '_ ` _ \\ / _` | '_ \\ ",
-            "|  _  | (_| | | | | (_| | | | | | | (_| | | | |",
-            "|_| |_|\\__,_|_| |_|\\__, |_| |_| |_|\\__,_|_| |_|",
-            "                   |___/                       ",
-            "===============================================",
-        ];
+        match game.status() {
+            GameStatus::InProgress => {
+                unreachable!("The loop above only ends when the game is complete.")
+            }
+            GameStatus::Complete(complete_game_status) => {
+                self.handle_complete_game(game, &complete_game_status)?;
+
+                Ok(GameResult::Complete(complete_game_status))
+            }
+        }
+    }
+
+    fn print_intro(&mut self) -> io::Result<()> {
+        let text = self.text();
+
+        if self.settings.accessible {
+            writeln!(self.output, "{}", text.welcome)?;
+            writeln!(self.output, "{}", text.intro_help)?;
 
-        let formatted_lines = lines
+            return writeln!(self.output);
+        }
+
+        writeln!(self.output, "{}", text.welcome_to)?;
+        writeln!(self.output)?;
+
+        let formatted_lines = BANNER_LINES
             .iter()
             .map(|&line| line.cyan().bold().to_string())
             .collect::<Vec<String>>();
 
         for formatted_line in formatted_lines {
-            println!("{}", formatted_line);
+            writeln!(self.output, "{}", formatted_line)?;
+        }
+
+        writeln!(self.output)?;
+
+        writeln!(self.output, "{}", text.intro_help.italic().dimmed())?;
+        writeln!(self.output)
+    }
+
+    fn play_guess_round(&mut self, game: &mut Game, lives: usize) -> io::Result<RoundResult> {
+        if self.settings.accessible {
+            self.output_accessible_game_state(game)?;
+        } else {
+            self.output_game_state(game)?;
+        }
+
+        let Some(input) = self.read_input()? else {
+            return Ok(RoundResult::Quit);
+        };
+        writeln!(self.output)?;
+
+        let command = match input.parse::<Command>() {
+            Ok(command) => command,
+            Err(error) => {
+                let message = format!("\u{2717} {}", error.message(self.locale()));
+
+                self.output_message(Tone::Negative, &message)?;
+                return Ok(RoundResult::Continue);
+            }
+        };
+
+        match command {
+            Command::Guess(guess) => {
+                self.handle_make_guess_result(&guess, game.make_guess(&guess))?
+            }
+            Command::Hint => self.handle_hint(game)?,
+            Command::Solve(attempt) => {
+                let solve_result = game.solve(&attempt);
+
+                self.handle_solve_result(&attempt, solve_result)?
+            }
+            Command::Undo => {
+                let undo_result = game.undo();
+                let (tone, message) =
+                    messages::undo_result(self.locale(), &undo_result, game.undos_remaining());
+
+                self.output_message(tone, &message)?
+            }
+            Command::Redo => {
+                let (tone, message) = messages::redo_result(self.locale(), &game.redo());
+
+                self.output_message(tone, &message)?
+            }
+            Command::New => {
+                self.output_message(Tone::Negative, self.text().new_game_started)?;
+
+                return Ok(RoundResult::NewGame);
+            }
+            Command::Quit => return Ok(RoundResult::Quit),
+            Command::Help => self.print_help(lives)?,
+            Command::Stats | Command::ShowSettings | Command::ChangeSetting { .. } => {
+                self.handle_session_command(command)?
+            }
         }
 
-        println!();
+        Ok(RoundResult::Continue)
     }
 
-    fn play_guess_round(game: &mut Game) {
-        println!("The word for you to guess is:");
-        println!();
+    fn output_game_state(&mut self, game: &Game) -> io::Result<()> {
+        let text = self.text();
+
+        writeln!(self.output, "{}", text.word_to_guess)?;
+        writeln!(self.output)?;
 
-        Self::output_current_word_state(game);
-        println!();
+        self.output_current_word_state(game)?;
+        writeln!(self.output)?;
 
-        Self::output_lives_remaining(game);
-        println!();
+        if let Some(entry) = game.entry() {
+            writeln!(
+                self.output,
+                "{}",
+                fill(text.category_label, &[("category", &entry.category)])
+            )?;
+        }
+
+        if let Some(clue) = messages::clue(self.locale(), game) {
+            writeln!(self.output, "{}", clue)?;
+        }
+
+        self.output_lives_remaining(game)?;
+        writeln!(self.output)?;
+
+        writeln!(self.output, "{}", text.guess_prompt.bold())?;
+        writeln!(self.output)?;
 
-        println!(
+        self.output_previous_guesses(game)?;
+        writeln!(self.output)
+    }
+
+    fn output_accessible_game_state(&mut self, game: &Game) -> io::Result<()> {
+        let locale = self.locale();
+
+        writeln!(
+            self.output,
+            "{}.",
+            accessibility::describe_word(locale, game)
+        )?;
+        if let Some(entry) = game.entry() {
+            writeln!(
+                self.output,
+                "{}.",
+                fill(
+                    locale.catalogue().category_label,
+                    &[("category", &entry.category)]
+                )
+            )?;
+        }
+        if let Some(clue) = messages::clue(locale, game) {
+            writeln!(self.output, "{}", clue)?;
+        }
+        writeln!(
+            self.output,
             "{}",
-            "Please guess a letter, and make it a good one!".bold()
-        );
-        println!();
+            accessibility::describe_guesses(locale, game)
+        )?;
+        writeln!(self.output, "{}", locale.catalogue().plain_guess_prompt)?;
+        writeln!(self.output)
+    }
+
+    /// Handles the commands that don't depend on a game being in progress.
+    fn handle_session_command(&mut self, command: Command) -> io::Result<()> {
+        let text = self.text();
+
+        match command {
+            Command::Stats => self.print_stats(),
+            Command::ShowSettings => self.print_settings(),
+            Command::ChangeSetting { name, value } => match self.change_setting(&name, &value) {
+                Ok(()) => self.output_message(
+                    Tone::Positive,
+                    &fill(text.setting_changed, &[("name", &name), ("value", &value)]),
+                ),
+                Err(message) => {
+                    self.output_message(Tone::Negative, &format!("\u{2717} {}", message))
+                }
+            },
+            _ => unreachable!("only session commands are passed here"),
+        }
+    }
+
+    /// Changes a setting, keeping the old settings if the new value isn't valid.
+    fn change_setting(&mut self, name: &str, value: &str) -> Result<(), String> {
+        let mut settings = self.settings.clone();
+
+        settings.set_in_session(name, value)?;
+        settings.validate_words(&self.words)?;
+
+        self.settings = settings;
+
+        Ok(())
+    }
+
+    fn handle_hint(&mut self, game: &mut Game) -> io::Result<()> {
+        let text = self.text();
+
+        match game.use_hint() {
+            Ok(letter) => {
+                self.output_message(Tone::Positive, &fill(text.hint, &[("letter", &letter)]))
+            }
+            Err(HintError::NotEnoughLives) => {
+                self.output_message(Tone::Negative, text.hint_not_enough_lives)
+            }
+            Err(HintError::GameComplete) => {
+                self.output_message(Tone::Negative, text.game_already_over)
+            }
+            Err(error) => self.output_message(Tone::Negative, &error.to_string()),
+        }
+    }
+
+    fn handle_solve_result(&mut self, attempt: &str, solve_result: SolveResult) -> io::Result<()> {
+        let text = self.text();
+        let attempt = attempt.trim().to_uppercase();
+
+        match solve_result {
+            Ok(true) => self.output_message(
+                Tone::Positive,
+                &fill(text.solve_correct, &[("word", &attempt)]),
+            ),
+            Ok(false) => self.output_message(
+                Tone::Negative,
+                &fill(text.solve_incorrect, &[("word", &attempt)]),
+            ),
+            Err(MakeGuessError::Invalid) => self.output_message(
+                Tone::Negative,
+                &fill(text.solve_invalid, &[("word", &attempt)]),
+            ),
+            Err(error) => self.handle_make_guess_result(&attempt, Err(error)),
+        }
+    }
+
+    fn print_help(&mut self, lives: usize) -> io::Result<()> {
+        let text = self.text();
+
+        writeln!(self.output, "{}", text.rules.bold().underline())?;
+        writeln!(self.output)?;
+
+        for rule in rules(&self.settings, lives) {
+            writeln!(self.output, "  - {}", rule)?;
+        }
+        writeln!(self.output)?;
+
+        writeln!(self.output, "{}", text.commands.bold().underline())?;
+        writeln!(self.output)?;
+
+        let width = COMMANDS
+            .iter()
+            .map(|usage| usage.len())
+            .max()
+            .unwrap_or_default();
+
+        writeln!(
+            self.output,
+            "  {}  {}",
+            format!("{:width$}", "A-Z").bold(),
+            text.guess_a_letter
+        )?;
+        for (usage, description) in COMMANDS.iter().zip(text.command_descriptions) {
+            writeln!(
+                self.output,
+                "  {}  {}",
+                format!("{:width$}", usage).bold(),
+                description
+            )?;
+        }
+        writeln!(self.output)?;
+        writeln!(self.output, "{}", text.history_tip.italic().dimmed())?;
+        writeln!(self.output)
+    }
+
+    fn print_stats(&mut self) -> io::Result<()> {
+        let text = self.text();
+        let games_played = self.summary.games_won + self.summary.games_lost;
+        let rows = [
+            (text.played, games_played),
+            (text.won_count, self.summary.games_won),
+            (text.lost_count, self.summary.games_lost),
+        ];
+        let width = rows
+            .iter()
+            .map(|(label, _)| label.chars().count())
+            .max()
+            .unwrap_or_default();
+
+        writeln!(self.output, "{}", text.statistics.bold().underline())?;
+        writeln!(self.output)?;
+        for (label, count) in rows {
+            writeln!(self.output, "  {:width$}  {}", label, count)?;
+        }
+        writeln!(self.output)
+    }
 
-        Self::output_previous_guesses(game);
-        println!();
+    fn print_settings(&mut self) -> io::Result<()> {
+        let text = self.text();
 
-        let guess = Self::read_input();
-        println!();
+        writeln!(self.output, "{}", text.settings.bold().underline())?;
+        writeln!(self.output)?;
+        writeln!(self.output, "  lives              {}", self.settings.lives)?;
+        writeln!(
+            self.output,
+            "  minimum_word_size  {}",
+            self.settings.minimum_word_size
+        )?;
+        writeln!(
+            self.output,
+            "  maximum_word_size  {}",
+            self.settings
+                .maximum_word_size
+                .map_or(String::from(NO_LIMIT), |limit| limit.to_string())
+        )?;
+        writeln!(
+            self.output,
+            "  unique_letters     {}",
+            self.settings
+                .unique_letters
+                .map_or(String::from(NO_LIMIT), |limit| limit.to_string())
+        )?;
+        writeln!(
+            self.output,
+            "  accessible         {}",
+            self.settings.accessible
+        )?;
+        writeln!(
+            self.output,
+            "  undo_allowance     {}",
+            self.settings.undo_allowance
+        )?;
+        writeln!(
+            self.output,
+            "  undo_cost          {}",
+            self.settings.undo_cost
+        )?;
+        writeln!(
+            self.output,
+            "  category           {} ({}, {})",
+            self.settings.category.as_deref().unwrap_or(ANY_CATEGORY),
+            ANY_CATEGORY,
+            self.settings.categories(&self.words).join(", ")
+        )?;
+        writeln!(
+            self.output,
+            "  daily_salt         {}",
+            self.settings.daily_salt
+        )?;
+        writeln!(
+            self.output,
+            "  repeat_after       {}%",
+            self.settings.repeat_after
+        )?;
+        writeln!(
+            self.output,
+            "  family_friendly    {}",
+            self.settings.family_friendly
+        )?;
+        writeln!(
+            self.output,
+            "  difficulty         {}",
+            self.settings.difficulty
+        )?;
+        writeln!(self.output)?;
+        writeln!(self.output, "{}", text.settings_tip.italic().dimmed())?;
+        writeln!(self.output)
+    }
 
-        Self::handle_make_guess_result(&guess, game.make_guess(&guess));
+    fn output_no_words(&mut self, error: &PickWordError) -> io::Result<()> {
+        self.output_message(
+            Tone::Negative,
+            &format!(
+                "\u{2717} {}",
+                messages::pick_word_error(self.settings.locale, error)
+            ),
+        )
     }
 
-    fn output_current_word_state(game: &Game) {
+    fn output_current_word_state(&mut self, game: &Game) -> io::Result<()> {
         let letters = game.blanked_out_letters();
 
-        println!("    {}", letters.join(" ").bold());
+        writeln!(self.output, "    {}", letters.join(" ").bold())
     }
 
-    fn output_unblanked_word(game: &Game) {
+    fn output_unblanked_word(&mut self, game: &Game) -> io::Result<()> {
         let letters = game.letters();
 
-        println!("    {}", letters.join(" ").bold());
+        writeln!(self.output, "    {}", letters.join(" ").bold())
     }
 
-    fn output_lives_remaining(game: &Game) {
-        println!(
-            "You have {} lives remaining.",
-            Self::format_lives_remaining(game.lives_remaining())
-        );
+    fn output_lives_remaining(&mut self, game: &Game) -> io::Result<()> {
+        let lives_remaining = game.lives_remaining();
+        let template = self
+            .locale()
+            .select(&self.text().lives_remaining, lives_remaining);
+
+        writeln!(
+            self.output,
+            "{}",
+            fill(
+                template,
+                &[("count", &Self::format_lives_remaining(lives_remaining))]
+            )
+        )
     }
 
     fn format_lives_remaining(lives_remaining: usize) -> String {
@@ -110,160 +600,677 @@ impl StdIOGameRunner {
         .to_string()
     }
 
-    fn output_previous_guesses(game: &Game) {
+    fn output_previous_guesses(&mut self, game: &Game) -> io::Result<()> {
+        let text = self.text();
         let guesses = game.guesses();
 
         if guesses.is_empty() {
-            println!("{}", "No previous guesses.".italic().dimmed());
-            return;
-        }
-
-        println!(
-            "Previous guesses: {}",
-            guesses
-                .iter()
-                .map(|(guess, status)| {
-                    format!(
-                        "{} {}",
-                        guess,
-                        match status {
-                            GuessStatus::Correct => "\u{2713}".green(),
-                            GuessStatus::Incorrect => "\u{2717}".red(),
-                        }
-                    )
-                })
-                .collect::<Vec<String>>()
-                .join(", ")
-        );
+            return writeln!(
+                self.output,
+                "{}",
+                text.no_previous_guesses.italic().dimmed()
+            );
+        }
+
+        let guesses = guesses
+            .iter()
+            .map(|(guess, status)| {
+                format!(
+                    "{} {}",
+                    guess,
+                    match status {
+                        GuessStatus::Correct => "\u{2713}".green(),
+                        GuessStatus::Incorrect => "\u{2717}".red(),
+                    }
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        writeln!(
+            self.output,
+            "{}",
+            fill(text.previous_guesses, &[("guesses", &guesses)])
+        )
     }
 
     fn handle_make_guess_result(
-        guess: &String,
-        make_guess_result: Result<MakeGuessSuccess, MakeGuessError>,
-    ) {
-        match make_guess_result {
-            Ok(result) => match result {
-                MakeGuessSuccess::Correct(guess) => {
-                    println!(
-                        "{}",
-                        format!("\u{2713} Awesome! \"{}\" is in the word! Nice job!", guess)
-                            .green()
-                    )
-                }
-                MakeGuessSuccess::Incorrect(guess) => {
-                    println!(
-                        "{}",
-                        format!("\u{2717} Sorry! \"{}\" is not in the word!", guess).red()
-                    )
-                }
-            },
-            Err(error) => match error {
-                MakeGuessError::Empty => {
-                    println!("{}", "\u{2717} Your guess was empty!".red())
-                }
-                MakeGuessError::TooLong => {
-                    println!(
-                        "{}",
-                        "\u{2717} You entered more than one character! That's cheating!".red()
-                    )
-                }
-                MakeGuessError::Invalid => {
-                    println!(
-                        "{}",
-                        format!(
-                            "\u{2717} You entered an invalid character! I don't know what to do with \"{}\".",
-                            guess
-                        ).red()
-                    )
-                }
-                MakeGuessError::AlreadyGuessed(guess) => {
-                    println!("{}", format!("You've already guessed \"{}\"!", guess).red())
-                }
-                MakeGuessError::GameComplete => {
-                    panic!("Cannot make a guess as the game is complete.")
-                }
-            },
+        &mut self,
+        guess: &str,
+        make_guess_result: MakeGuessResult,
+    ) -> io::Result<()> {
+        let (tone, message) = messages::make_guess_result(self.locale(), guess, &make_guess_result);
+
+        self.output_message(tone, &message)
+    }
+
+    fn output_message(&mut self, tone: Tone, message: &str) -> io::Result<()> {
+        if self.settings.accessible {
+            writeln!(self.output, "{}", accessibility::without_glyph(message))?;
+
+            return writeln!(self.output);
         }
 
-        println!();
+        writeln!(
+            self.output,
+            "{}",
+            match tone {
+                Tone::Positive => message.green(),
+                Tone::Negative => message.red(),
+            }
+        )?;
+
+        writeln!(self.output)
     }
 
-    fn handle_complete_game(game: &Game, complete_game_status: &CompleteGameStatus) {
+    fn handle_complete_game(
+        &mut self,
+        game: &Game,
+        complete_game_status: &CompleteGameStatus,
+    ) -> io::Result<()> {
+        let text = self.text();
+
+        if let Some(description) = accessibility::describe_complete_game(self.locale(), game)
+            .filter(|_| self.settings.accessible)
+        {
+            writeln!(self.output, "{}", description)?;
+
+            for line in messages::definition(self.locale(), game) {
+                writeln!(self.output, "{}", line)?;
+            }
+
+            return writeln!(self.output);
+        }
+
         match complete_game_status {
             CompleteGameStatus::Won => {
-                let lives_remaining = game.lives_remaining();
-
-                println!(
-                    "{}",
-                    format!(
-                        "Well done! You guessed the word with {} {} remaining!",
-                        lives_remaining,
-                        if lives_remaining != 1 {
-                            "guesses"
-                        } else {
-                            "guess"
-                        }
-                    )
-                    .on_bright_green()
-                    .bright_white()
-                );
-                println!();
-
-                Self::output_current_word_state(game);
-                println!();
+                let message = self.locale().plural(&text.won, game.lives_remaining());
+
+                writeln!(self.output, "{}", message.on_bright_green().bright_white())?;
+                writeln!(self.output)?;
+
+                self.output_current_word_state(game)?;
+                writeln!(self.output)?;
             }
             CompleteGameStatus::Lost => {
-                println!(
-                    "{}",
-                    "Oh no! You ran out of lives! I'll tell you what is was though:"
-                        .on_bright_red()
-                        .bright_white()
-                );
-                println!();
-
-                Self::output_current_word_state(game);
-                println!();
-
-                Self::output_unblanked_word(game);
-                println!();
+                writeln!(self.output, "{}", text.lost.on_bright_red().bright_white())?;
+                writeln!(self.output)?;
+
+                self.output_current_word_state(game)?;
+                writeln!(self.output)?;
+
+                self.output_unblanked_word(game)?;
+                writeln!(self.output)?;
             }
         }
+
+        self.output_definition(game)
+    }
+
+    fn output_definition(&mut self, game: &Game) -> io::Result<()> {
+        let lines = messages::definition(self.locale(), game);
+
+        if lines.is_empty() {
+            return Ok(());
+        }
+
+        for line in lines {
+            writeln!(self.output, "{}", line.italic())?;
+        }
+
+        writeln!(self.output)
+    }
+
+    fn output_daily_share(&mut self, record: &DailyRecord) -> io::Result<()> {
+        let text = self.text();
+
+        if self.settings.accessible {
+            writeln!(self.output, "{}", text.share_result)?;
+        } else {
+            writeln!(self.output, "{}", text.share_result.bold())?;
+        }
+        writeln!(self.output)?;
+
+        for line in messages::daily_share(self.locale(), record) {
+            writeln!(self.output, "{}", line)?;
+        }
+
+        writeln!(self.output)
     }
 
-    fn prompt_for_new_game() -> bool {
+    fn prompt_for_new_game(&mut self) -> io::Result<bool> {
+        let text = self.text();
+
         loop {
-            println!(
-                "Would you like to play again? {}",
-                "(y/n)".dimmed().italic()
-            );
-            println!();
+            writeln!(
+                self.output,
+                "{} {}",
+                text.play_again,
+                text.play_again_choices.dimmed().italic()
+            )?;
+            writeln!(self.output)?;
 
-            let input = Self::read_input().to_lowercase();
-            println!();
+            let Some(input) = self.read_input()? else {
+                break Ok(false);
+            };
+            writeln!(self.output)?;
 
-            if input == "y" {
-                break true;
-            } else if input == "n" {
-                break false;
+            match input.parse::<Command>() {
+                Ok(Command::New) => break Ok(true),
+                Ok(Command::Quit) => break Ok(false),
+                Ok(Command::Guess(answer)) => match answer.trim().to_lowercase() {
+                    answer if answer == text.yes_answer => break Ok(true),
+                    answer if answer == text.no_answer => break Ok(false),
+                    _ => {}
+                },
+                Ok(Command::Help) => self.print_help(self.settings.lives)?,
+                Ok(
+                    command @ (Command::Stats
+                    | Command::ShowSettings
+                    | Command::ChangeSetting { .. }),
+                ) => self.handle_session_command(command)?,
+                Ok(Command::Hint | Command::Solve(_) | Command::Undo | Command::Redo) => {
+                    self.output_message(Tone::Negative, text.only_during_a_game)?
+                }
+                Err(error) => {
+                    let message = format!("\u{2717} {}", error.message(self.locale()));
+
+                    self.output_message(Tone::Negative, &message)?
+                }
             }
         }
     }
 
-    fn read_input() -> String {
-        let mut result = Self::read_line();
+    fn print_session_summary(&mut self) -> io::Result<()> {
+        let text = self.text();
+        let summary = &self.summary;
+        let games_played = summary.games_won + summary.games_lost;
+
+        writeln!(self.output)?;
+        writeln!(self.output, "{}", text.thanks_for_playing.bold())?;
+        writeln!(
+            self.output,
+            "{}",
+            fill(
+                &self.locale().plural(&text.session_summary, games_played),
+                &[("won", &summary.games_won), ("lost", &summary.games_lost)]
+            )
+        )
+    }
+
+    /// Reads the next line of input, returning `None` once the input has ended (e.g. Ctrl-D).
+    fn read_input(&mut self) -> io::Result<Option<String>> {
+        loop {
+            match self.read_line() {
+                Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+                Err(error) if error.kind() == ErrorKind::InvalidData => {
+                    writeln!(self.output, "{}", self.text().unreadable_input.red())?;
+                }
+                result => break result,
+            }
+        }
+    }
 
-        while result.is_err() {
-            println!();
+    fn read_line(&mut self) -> io::Result<Option<String>> {
+        let mut buffer: String = String::new();
 
-            result = Self::read_line();
+        if self.input.read_line(&mut buffer)? == 0 {
+            return Ok(None);
         }
 
-        result.unwrap()
+        Ok(Some(buffer.trim_end_matches(&['\r', '\n'][..]).to_string()))
     }
+}
 
-    fn read_line() -> Result<String, Error> {
-        let mut buffer: String = String::new();
-        stdin().read_line(&mut buffer)?;
-        Ok(buffer.trim_end_matches(&['\r', '\n'][..]).to_string())
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hangman::words::{Dictionary, RecentWords};
+
+    #[test]
+    fn a_scripted_session_that_guesses_every_letter_wins_the_game() {
+        let (output, _) = play_scripted_game("win", 3, "w\ni\nn\n");
+
+        assert!(output.contains("Awesome! \"W\" is in the word! Nice job!"));
+        assert!(output.contains("Well done! You guessed the word with 3 guesses remaining!"));
+        assert!(output.contains("    W I N"));
+    }
+
+    #[test]
+    fn a_scripted_session_that_runs_out_of_lives_loses_the_game_and_reveals_the_word() {
+        let (output, _) = play_scripted_game("win", 2, "a\nb\n");
+
+        assert!(output.contains("Sorry! \"A\" is not in the word!"));
+        assert!(output.contains("You have 1 life remaining."));
+        assert!(output.contains("Oh no! You ran out of lives!"));
+        assert!(output.contains("    _ _ _"));
+        assert!(output.contains("    W I N"));
+    }
+
+    #[test]
+    fn a_scripted_session_reports_invalid_input_without_losing_a_life() {
+        let (output, game) = play_scripted_game("a", 1, "\nab\n1\nhelp\na\n");
+
+        assert!(output.contains("Your guess was empty!"));
+        assert!(output.contains("You entered more than one character! That's cheating!"));
+        assert!(output.contains("I don't know what to do with \"1\"."));
+        assert!(output.contains("You get 1 life."));
+        assert_eq!(game.lives_remaining(), 1);
+        assert_eq!(game.status(), GameStatus::Complete(CompleteGameStatus::Won));
+    }
+
+    #[test]
+    fn a_scripted_session_reports_letters_that_have_already_been_guessed() {
+        let (output, _) = play_scripted_game("win", 3, "w\nw\ni\nn\n");
+
+        assert!(output.contains("You've already guessed \"W\"!"));
+        assert!(output.contains("Previous guesses: W \u{2713}"));
+    }
+
+    #[test]
+    fn answering_no_to_the_play_again_prompt_quits() {
+        let (output, play_again) = prompt_for_new_game("maybe\nN\n");
+
+        assert!(!play_again);
+        assert_eq!(output.matches("Would you like to play again?").count(), 2);
+    }
+
+    #[test]
+    fn answering_yes_to_the_play_again_prompt_starts_a_new_game() {
+        let (_, play_again) = prompt_for_new_game("y\n");
+
+        assert!(play_again);
+    }
+
+    #[test]
+    fn reaching_the_end_of_input_at_the_play_again_prompt_quits() {
+        let (_, play_again) = prompt_for_new_game("");
+
+        assert!(!play_again);
+    }
+
+    #[test]
+    fn a_session_plays_games_until_the_player_answers_no_and_then_prints_a_summary() {
+        let (output, summary) = run_scripted_session("a", 1, "a\ny\nb\nn\n");
+
+        assert_eq!(
+            summary,
+            SessionSummary {
+                games_won: 1,
+                games_lost: 1,
+            }
+        );
+        assert!(output.ends_with("Thanks for playing!\nYou played 2 games, won 1 and lost 1.\n"));
+    }
+
+    #[test]
+    fn typing_quit_during_a_game_ends_the_session_without_counting_the_game() {
+        let (output, summary) = run_scripted_session("win", 3, "w\nQUIT\nn\n");
+
+        assert_eq!(summary, SessionSummary::default());
+        assert!(!output.contains("Would you like to play again?"));
+        assert!(output.ends_with("You played 0 games, won 0 and lost 0.\n"));
+    }
+
+    #[test]
+    fn reaching_the_end_of_input_during_a_game_ends_the_session() {
+        let (output, summary) = run_scripted_session("win", 3, "w\ni\n");
+
+        assert_eq!(summary, SessionSummary::default());
+        assert!(output.contains("Thanks for playing!"));
+    }
+
+    #[test]
+    fn unreadable_input_is_reported_and_the_next_line_is_read() {
+        colored::control::set_override(false);
+
+        let mut game = Game::from_word("a", 1).unwrap();
+        let mut output = Vec::new();
+        let input: &[u8] = b"\xff\na\n";
+
+        let result =
+            StdIOGameRunner::new(input, &mut output, Settings::default(), WordList::default())
+                .play_game(&mut game, 1)
+                .unwrap();
+
+        assert_eq!(result, GameResult::Complete(CompleteGameStatus::Won));
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .contains("Sorry, I couldn't read that."));
+    }
+
+    #[test]
+    fn a_hint_reveals_a_letter_at_the_cost_of_a_life() {
+        let (output, game) = play_scripted_game("aa", 3, "/hint\n");
+
+        assert!(output.contains("Here's a hint: \"A\" is in the word."));
+        assert_eq!(game.lives_remaining(), 2);
+        assert_eq!(game.status(), GameStatus::Complete(CompleteGameStatus::Won));
+    }
+
+    #[test]
+    fn solving_the_word_wins_the_game_and_a_wrong_attempt_costs_a_life() {
+        let (output, game) = play_scripted_game("win", 3, "/solve won\n/solve Win\n");
+
+        assert!(output.contains("Sorry! The word isn't \"WON\"!"));
+        assert!(output.contains("Amazing! \"WIN\" is the word!"));
+        assert_eq!(game.lives_remaining(), 2);
+        assert_eq!(game.status(), GameStatus::Complete(CompleteGameStatus::Won));
+    }
+
+    #[test]
+    fn unknown_commands_and_missing_arguments_are_reported_without_losing_a_life() {
+        let (output, game) = play_scripted_game("a", 1, "/jump\n/solve\na\n");
+
+        assert!(output.contains("I don't know the command \"/jump\"."));
+        assert!(output.contains("Usage: /solve WORD"));
+        assert_eq!(game.lives_remaining(), 1);
+    }
+
+    #[test]
+    fn starting_a_new_game_abandons_the_current_one_without_counting_it() {
+        let (output, summary) = run_scripted_session("a", 1, "/new\na\n/stats\nn\n");
+
+        assert!(output.contains("Giving up on this word."));
+        assert!(output.contains("  Played  1\n  Won     1\n  Lost    0\n"));
+        assert_eq!(
+            summary,
+            SessionSummary {
+                games_won: 1,
+                games_lost: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn changing_a_setting_applies_it_to_the_next_game() {
+        colored::control::set_override(false);
+
+        let mut output = Vec::new();
+        let mut lives_per_game = Vec::new();
+        let input: &[u8] =
+            b"/settings lives 0\n/settings family_friendly off\n/settings lives 5\na\ny\na\nn\n";
+        let words = WordList::parse("@language en\n@category animals\nhorse\n").unwrap();
+
+        StdIOGameRunner::new(input, &mut output, Settings::default(), words)
+            .run_session(|settings, _| {
+                lives_per_game.push(settings.lives);
+
+                Ok(Game::from_word("a", settings.lives).unwrap())
+            })
+            .unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(output.contains("\"lives\" must be a whole number greater than 0"));
+        assert!(output.contains(
+            "\"family_friendly\" takes effect on restart, so change it in the config file"
+        ));
+        assert!(output.contains("\"lives\" is now 5. This applies from the next game."));
+        assert_eq!(lives_per_game, vec![10, 5]);
+    }
+
+    #[test]
+    fn when_no_word_matches_the_settings_the_session_ends_with_a_suggestion() {
+        colored::control::set_override(false);
+
+        let words = WordList::parse("@language en\n@category animals\ncat\n").unwrap();
+        let mut output = Vec::new();
+
+        let summary = StdIOGameRunner::new(&b""[..], &mut output, Settings::default(), words)
+            .run_session(|settings, words| {
+                settings.new_game(words, &RecentWords::new(), settings.lives)
+            })
+            .unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(output.contains(
+            "\u{2717} No word could be picked: there are no words in \"en\" with at least 4 \
+             letters. Try a smaller minimum word size"
+        ));
+        assert_eq!(summary, SessionSummary::default());
+    }
+
+    #[test]
+    fn the_category_is_shown_during_play_and_can_only_be_changed_to_one_with_words() {
+        colored::control::set_override(false);
+
+        let words = WordList::parse("@language en\n@category animals\ncat\n@category food\nkiwi\n")
+            .unwrap();
+        let settings = Settings {
+            minimum_word_size: 1,
+            category: Some(String::from("animals")),
+            ..Settings::default()
+        };
+        let mut output = Vec::new();
+        let input: &[u8] =
+            b"/settings category sport\n/settings category Food\nc\na\nt\ny\nk\ni\nw\nn\n";
+
+        let summary = StdIOGameRunner::new(input, &mut output, settings, words)
+            .run_session(|settings, words| {
+                settings.new_game(words, &RecentWords::new(), settings.lives)
+            })
+            .unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(output.contains("Category: animals\nYou have 10 lives remaining."));
+        assert!(output
+            .contains("\u{2717} unknown category \"sport\", expected one of: any, animals, food"));
+        assert!(output.contains("\"category\" is now Food. This applies from the next game."));
+        assert!(output.contains("Category: food\n"));
+        assert_eq!(summary.games_won, 2);
+    }
+
+    #[test]
+    fn the_definition_of_the_word_is_shown_when_the_game_ends() {
+        colored::control::set_override(false);
+
+        let mut words = WordList::parse("@language en\n@category animals\ncat\n").unwrap();
+        words.define(
+            &Dictionary::parse("@language en\ncat | noun | A small furry pet. | It purred.\n")
+                .unwrap(),
+        );
+        let settings = Settings {
+            lives: 1,
+            minimum_word_size: 1,
+            ..Settings::default()
+        };
+        let mut output = Vec::new();
+
+        StdIOGameRunner::new(b"z\nn\n".as_slice(), &mut output, settings, words)
+            .run_session(|settings, words| {
+                settings.new_game(words, &RecentWords::new(), settings.lives)
+            })
+            .unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(output.contains(
+            "    C A T\n\nCAT (noun): A small furry pet.\nFor example: \"It purred.\"\n"
+        ));
+    }
+
+    #[test]
+    fn the_clue_is_shown_while_guessing_and_stands_in_for_a_missing_definition() {
+        colored::control::set_override(false);
+
+        let words =
+            WordList::parse("@language en\n@category animals\ncat | A small furry pet\n").unwrap();
+        let settings = Settings {
+            lives: 1,
+            minimum_word_size: 1,
+            ..Settings::default()
+        };
+        let mut output = Vec::new();
+
+        StdIOGameRunner::new(b"z\nn\n".as_slice(), &mut output, settings, words)
+            .run_session(|settings, words| {
+                settings.new_game(words, &RecentWords::new(), settings.lives)
+            })
+            .unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(output.contains("Category: animals\nClue: A small furry pet\n"));
+        assert!(output.contains("    C A T\n\nCAT: A small furry pet\n"));
+    }
+
+    #[test]
+    fn the_word_of_the_day_is_played_once_and_its_result_can_be_shared() {
+        colored::control::set_override(false);
+
+        // The word of the day is "RUST" from the built-in words, whatever words are loaded.
+        let words = WordList::parse("@language en\n@category animals\nhorse\n").unwrap();
+        let mut records = DailyRecords::default();
+        let date = Date::new(2024, 2, 29).unwrap();
+        let mut play_daily = |input: &str| {
+            let mut output = Vec::new();
+
+            StdIOGameRunner::new(
+                input.as_bytes(),
+                &mut output,
+                Settings::default(),
+                words.clone(),
+            )
+            .run_daily(&mut records, date)
+            .unwrap();
+
+            String::from_utf8(output).unwrap()
+        };
+
+        let first_output = play_daily("z\nr\nu\ns\nt\n");
+        let second_output = play_daily("");
+
+        assert!(first_output.contains(
+            "Share your result:\n\nHangman 2024-02-29: solved with 9/10 lives left\n\
+             \u{1F7E5}\u{1F7E9}\u{1F7E9}\u{1F7E9}\u{1F7E9}\n"
+        ));
+        assert!(second_output.contains("You've already played today's word."));
+        assert!(second_output.contains("Hangman 2024-02-29: solved with 9/10 lives left"));
+        assert!(!second_output.contains("The word for you to guess is:"));
+    }
+
+    #[test]
+    fn undo_takes_back_a_guess_and_redo_makes_it_again() {
+        let (output, game) = play_scripted_game("crate", 5, "z\n/undo\n/redo\n/undo\n/undo\n");
+
+        assert!(output.contains("Took back \"Z\". You have 2 undos left in this game."));
+        assert!(output.contains("Made \"Z\" again."));
+        assert!(output.contains("Took back \"Z\". You have 1 undo left in this game."));
+        assert!(output.contains("There's nothing to take back!"));
+        assert_eq!(game.lives_remaining(), 5);
+        assert!(game.guesses().is_empty());
+    }
+
+    #[test]
+    fn accessible_mode_describes_the_game_in_sentences_without_glyphs() {
+        colored::control::set_override(false);
+
+        let mut game = Game::from_word("win", 2).unwrap();
+        let mut output = Vec::new();
+        let settings = Settings {
+            accessible: true,
+            ..Settings::default()
+        };
+
+        StdIOGameRunner::new(
+            b"i\nz\nw\nn\n".as_slice(),
+            &mut output,
+            settings,
+            WordList::default(),
+        )
+        .play_game(&mut game, 2)
+        .unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(output.contains("3 letters, second letter is I, 2 lives left."));
+        assert!(output.contains("In the word: I. Not in the word: Z."));
+        assert!(output.contains("\nAwesome! \"W\" is in the word! Nice job!"));
+        assert!(output.contains("You won with 1 life left! The word was WIN."));
+        assert!(!output.contains('\u{2713}'));
+        assert!(!output.contains("_ _"));
+    }
+
+    #[test]
+    fn a_session_in_another_language_uses_its_messages_and_answers() {
+        colored::control::set_override(false);
+
+        let mut output = Vec::new();
+        let settings = Settings {
+            lives: 1,
+            locale: Locale::French,
+            ..Settings::default()
+        };
+
+        StdIOGameRunner::new(
+            b"a\no\nb\nn\n".as_slice(),
+            &mut output,
+            settings,
+            WordList::default(),
+        )
+        .run_session(|settings, _| Ok(Game::from_word("a", settings.lives).unwrap()))
+        .unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(output.contains("Il vous reste 1 vie."));
+        assert!(output.contains("Voulez-vous rejouer ? (o/n)"));
+        assert!(output.ends_with("Vous avez joué 2 parties, gagné 1 et perdu 1.\n"));
+    }
+
+    fn play_scripted_game(word: &str, lives: usize, input: &str) -> (String, Game) {
+        colored::control::set_override(false);
+
+        let mut game = Game::from_word(word, lives).unwrap();
+        let mut output = Vec::new();
+
+        StdIOGameRunner::new(
+            input.as_bytes(),
+            &mut output,
+            Settings::default(),
+            WordList::default(),
+        )
+        .play_game(&mut game, lives)
+        .unwrap();
+
+        (String::from_utf8(output).unwrap(), game)
+    }
+
+    fn run_scripted_session(word: &str, lives: usize, input: &str) -> (String, SessionSummary) {
+        colored::control::set_override(false);
+
+        let mut output = Vec::new();
+
+        let settings = Settings {
+            lives,
+            ..Settings::default()
+        };
+
+        let summary =
+            StdIOGameRunner::new(input.as_bytes(), &mut output, settings, WordList::default())
+                .run_session(|_, _| Ok(Game::from_word(word, lives).unwrap()))
+                .unwrap();
+
+        (String::from_utf8(output).unwrap(), summary)
+    }
+
+    fn prompt_for_new_game(input: &str) -> (String, bool) {
+        colored::control::set_override(false);
+
+        let mut output = Vec::new();
+
+        let play_again = StdIOGameRunner::new(
+            input.as_bytes(),
+            &mut output,
+            Settings::default(),
+            WordList::default(),
+        )
+        .prompt_for_new_game()
+        .unwrap();
+
+        (String::from_utf8(output).unwrap(), play_again)
     }
 }
@@ -0,0 +1,160 @@
+use crate::i18n::{fill, Locale};
+use std::str::FromStr;
+
+/// The usage of each command, described by `command_descriptions` in the message catalogue.
+pub(crate) const COMMANDS: [&str; 10] = [
+    "/hint",
+    "/solve WORD",
+    "/undo",
+    "/redo",
+    "/stats",
+    "/new",
+    "/settings",
+    "/settings NAME VALUE",
+    "/help",
+    "/quit",
+];
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Command {
+    Guess(String),
+    Hint,
+    Solve(String),
+    Undo,
+    Redo,
+    Stats,
+    New,
+    Quit,
+    Help,
+    ShowSettings,
+    ChangeSetting { name: String, value: String },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum ParseCommandError {
+    Unknown(String),
+    MissingArgument(&'static str),
+    UnexpectedArgument(&'static str),
+}
+
+impl ParseCommandError {
+    pub(crate) fn message(&self, locale: Locale) -> String {
+        let text = locale.catalogue();
+
+        match self {
+            ParseCommandError::Unknown(command) => {
+                fill(text.unknown_command, &[("command", command)])
+            }
+            ParseCommandError::MissingArgument(usage) => {
+                fill(text.command_usage, &[("usage", usage)])
+            }
+            ParseCommandError::UnexpectedArgument(command) => {
+                fill(text.command_takes_no_arguments, &[("command", command)])
+            }
+        }
+    }
+}
+
+impl FromStr for Command {
+    type Err = ParseCommandError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let trimmed = input.trim();
+
+        let Some(command) = trimmed.strip_prefix('/') else {
+            return Ok(match trimmed.to_lowercase().as_str() {
+                "help" => Command::Help,
+                "quit" | "exit" => Command::Quit,
+                _ => Command::Guess(input.to_string()),
+            });
+        };
+
+        let mut parts = command.split_whitespace();
+        let name = parts.next().unwrap_or_default().to_lowercase();
+        let arguments = parts.collect::<Vec<&str>>();
+
+        let without_arguments = |command: Command, usage: &'static str| {
+            if arguments.is_empty() {
+                Ok(command)
+            } else {
+                Err(ParseCommandError::UnexpectedArgument(usage))
+            }
+        };
+
+        match name.as_str() {
+            "hint" => without_arguments(Command::Hint, "/hint"),
+            "undo" => without_arguments(Command::Undo, "/undo"),
+            "redo" => without_arguments(Command::Redo, "/redo"),
+            "stats" => without_arguments(Command::Stats, "/stats"),
+            "new" => without_arguments(Command::New, "/new"),
+            "quit" | "exit" => without_arguments(Command::Quit, "/quit"),
+            "help" => without_arguments(Command::Help, "/help"),
+            "solve" => match arguments.as_slice() {
+                [word] => Ok(Command::Solve(word.to_string())),
+                _ => Err(ParseCommandError::MissingArgument("/solve WORD")),
+            },
+            "settings" => match arguments.as_slice() {
+                [] => Ok(Command::ShowSettings),
+                [name, value] => Ok(Command::ChangeSetting {
+                    name: name.to_lowercase(),
+                    value: value.to_string(),
+                }),
+                _ => Err(ParseCommandError::MissingArgument("/settings NAME VALUE")),
+            },
+            _ => Err(ParseCommandError::Unknown(trimmed.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_without_a_slash_is_a_guess() {
+        assert_eq!("a".parse(), Ok(Command::Guess(String::from("a"))));
+        assert_eq!("".parse(), Ok(Command::Guess(String::new())));
+    }
+
+    #[test]
+    fn help_and_quit_work_with_or_without_a_slash() {
+        assert_eq!("HELP".parse(), Ok(Command::Help));
+        assert_eq!("/help".parse(), Ok(Command::Help));
+        assert_eq!("exit".parse(), Ok(Command::Quit));
+        assert_eq!("/Quit".parse(), Ok(Command::Quit));
+    }
+
+    #[test]
+    fn commands_with_arguments_are_parsed() {
+        assert_eq!(
+            "/solve crate".parse(),
+            Ok(Command::Solve(String::from("crate")))
+        );
+        assert_eq!("/undo".parse(), Ok(Command::Undo));
+        assert_eq!("/REDO".parse(), Ok(Command::Redo));
+        assert_eq!("/settings".parse(), Ok(Command::ShowSettings));
+        assert_eq!(
+            "/settings Lives 5".parse(),
+            Ok(Command::ChangeSetting {
+                name: String::from("lives"),
+                value: String::from("5"),
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_commands_return_an_error() {
+        assert_eq!(
+            "/jump".parse::<Command>(),
+            Err(ParseCommandError::Unknown(String::from("/jump")))
+        );
+        assert_eq!(
+            "/solve".parse::<Command>(),
+            Err(ParseCommandError::MissingArgument("/solve WORD"))
+        );
+        assert_eq!(
+            "/hint please".parse::<Command>(),
+            Err(ParseCommandError::UnexpectedArgument("/hint"))
+        );
+    }
+}
@@ -0,0 +1,66 @@
+use rustyline::{error::ReadlineError, DefaultEditor};
+use std::io::{self, BufRead, Read};
+
+const PROMPT: &str = "> ";
+
+/// Adapts a `rustyline` editor into a `BufRead`, so interactive players get line editing and
+/// history (with the up and down arrows) while the runner keeps reading lines as usual.
+pub(crate) struct LineEditor {
+    editor: DefaultEditor,
+    buffer: Vec<u8>,
+    position: usize,
+    ended: bool,
+}
+
+impl LineEditor {
+    pub(crate) fn new() -> rustyline::Result<Self> {
+        Ok(LineEditor {
+            editor: DefaultEditor::new()?,
+            buffer: Vec::new(),
+            position: 0,
+            ended: false,
+        })
+    }
+}
+
+impl Read for LineEditor {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let available = self.fill_buf()?;
+        let amount = available.len().min(buf.len());
+
+        buf[..amount].copy_from_slice(&available[..amount]);
+        self.consume(amount);
+
+        Ok(amount)
+    }
+}
+
+impl BufRead for LineEditor {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.position >= self.buffer.len() && !self.ended {
+            self.position = 0;
+            self.buffer.clear();
+
+            match self.editor.readline(PROMPT) {
+                Ok(line) => {
+                    if !line.trim().is_empty() {
+                        self.editor
+                            .add_history_entry(line.as_str())
+                            .map_err(io::Error::other)?;
+                    }
+
+                    self.buffer = format!("{}\n", line).into_bytes();
+                }
+                Err(ReadlineError::Eof | ReadlineError::Interrupted) => self.ended = true,
+                Err(ReadlineError::Io(error)) => return Err(error),
+                Err(error) => return Err(io::Error::other(error)),
+            }
+        }
+
+        Ok(&self.buffer[self.position..])
+    }
+
+    fn consume(&mut self, amount: usize) {
+        self.position += amount;
+    }
+}
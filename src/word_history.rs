@@ -0,0 +1,188 @@
+use crate::config::Config;
+use hangman::{words::RecentWords, Game};
+use std::{
+    fmt, fs,
+    io::{self, ErrorKind},
+    path::PathBuf,
+};
+
+const WORD_HISTORY_FILE: &str = "recent_words.txt";
+
+/// The words played lately, saved to `recent_words.txt` next to the config file with one
+/// language code and word per line, oldest first.
+#[derive(Debug, Default)]
+pub(crate) struct WordHistory {
+    path: Option<PathBuf>,
+    recent: RecentWords,
+}
+
+#[derive(Debug)]
+pub(crate) enum WordHistoryError {
+    Io(PathBuf, io::Error),
+    Invalid { path: PathBuf, line: usize },
+}
+
+impl fmt::Display for WordHistoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WordHistoryError::Io(path, error) => {
+                write!(f, "could not read {}: {}", path.display(), error)
+            }
+            WordHistoryError::Invalid { path, line } => write!(
+                f,
+                "recent words {} line {}: expected a language code and a word",
+                path.display(),
+                line
+            ),
+        }
+    }
+}
+
+impl WordHistory {
+    /// Loads the history, or starts an empty one that replaces the file if it can't be read, along
+    /// with why. Losing the history only means recent words may come up again sooner.
+    pub(crate) fn load() -> (Self, Option<WordHistoryError>) {
+        match Config::path().and_then(|path| Some(path.parent()?.join(WORD_HISTORY_FILE))) {
+            Some(path) => Self::load_or_empty(path),
+            None => (WordHistory::default(), None),
+        }
+    }
+
+    fn load_or_empty(path: PathBuf) -> (Self, Option<WordHistoryError>) {
+        match Self::load_from(path.clone()) {
+            Ok(history) => (history, None),
+            Err(error) => (
+                WordHistory {
+                    path: Some(path),
+                    recent: RecentWords::new(),
+                },
+                Some(error),
+            ),
+        }
+    }
+
+    fn load_from(path: PathBuf) -> Result<Self, WordHistoryError> {
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == ErrorKind::NotFound => String::new(),
+            Err(error) => return Err(WordHistoryError::Io(path, error)),
+        };
+
+        let mut recent = RecentWords::new();
+
+        for (index, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            match line.split_whitespace().collect::<Vec<&str>>().as_slice() {
+                [language, word] => recent.record(language, word),
+                _ => {
+                    return Err(WordHistoryError::Invalid {
+                        path,
+                        line: index + 1,
+                    })
+                }
+            }
+        }
+
+        Ok(WordHistory {
+            path: Some(path),
+            recent,
+        })
+    }
+
+    pub(crate) fn recent(&self) -> &RecentWords {
+        &self.recent
+    }
+
+    /// Adds the word of a game that has started and saves the history. Failing to save only means
+    /// the word may come up again sooner, so the error is left for the caller to ignore.
+    pub(crate) fn record(&mut self, game: &Game) -> io::Result<()> {
+        let Some(entry) = game.entry() else {
+            return Ok(());
+        };
+
+        self.recent.record(&entry.language, &entry.word);
+        self.save()
+    }
+
+    /// Forgets every word, so that any word can come up again.
+    pub(crate) fn clear(&mut self) -> io::Result<()> {
+        self.recent.clear();
+        self.save()
+    }
+
+    fn save(&self) -> io::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory)?;
+        }
+
+        fs::write(
+            path,
+            self.recent
+                .iter()
+                .map(|(language, word)| format!("{} {}\n", language, word))
+                .collect::<String>(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hangman::{words::WordList, GameBuilder};
+    use std::{env, process};
+
+    #[test]
+    fn played_words_are_saved_loaded_and_cleared() {
+        let directory = env::temp_dir().join(format!("hangman-history-{}", process::id()));
+        let path = directory.join(WORD_HISTORY_FILE);
+        let words = WordList::parse("@language fr\n@category animaux\nchat\n").unwrap();
+        let game = GameBuilder::new()
+            .entry(words.entries()[0].clone())
+            .build()
+            .unwrap();
+
+        let mut history = WordHistory::load_from(path.clone()).unwrap();
+
+        history.record(&game).unwrap();
+        history.record(&Game::from_word("dog", 5).unwrap()).unwrap();
+
+        let contents = fs::read_to_string(&path).unwrap();
+        let loaded = WordHistory::load_from(path.clone()).unwrap();
+
+        history.clear().unwrap();
+        let cleared = WordHistory::load_from(path.clone()).unwrap();
+
+        fs::write(&path, "en CAT\nHORSE\n").unwrap();
+        let (mut emptied, error) = WordHistory::load_or_empty(path.clone());
+
+        emptied.record(&game).unwrap();
+        let replaced = fs::read_to_string(&path).unwrap();
+
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(contents, "fr CHAT\n");
+        assert_eq!(
+            loaded.recent().iter().collect::<Vec<(&str, &str)>>(),
+            [("fr", "CHAT")]
+        );
+        assert!(cleared.recent().is_empty());
+        assert_eq!(replaced, "fr CHAT\n");
+        assert_eq!(
+            error.map(|error| error.to_string()).as_deref(),
+            Some(
+                format!(
+                    "recent words {} line 2: expected a language code and a word",
+                    path.display()
+                )
+                .as_str()
+            )
+        );
+    }
+}
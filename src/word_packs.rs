@@ -0,0 +1,266 @@
+use crate::config::Config;
+use hangman::words::{
+    Dictionary, DictionaryError, ScreenKind, WordList, WordPackError, WordScreen, WordScreenError,
+};
+use std::{
+    fmt, fs,
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+const PACKS_DIRECTORY: &str = "packs";
+const PACK_EXTENSION: &str = "pack";
+const DICTIONARY_EXTENSION: &str = "dict";
+const BLOCKLIST_EXTENSION: &str = "block";
+const ALLOWLIST_EXTENSION: &str = "allow";
+
+#[derive(Debug)]
+pub(crate) enum LoadWordPacksError {
+    Io(PathBuf, io::Error),
+    Invalid(PathBuf, WordPackError),
+    InvalidDictionary(PathBuf, DictionaryError),
+    InvalidScreen(PathBuf, ScreenKind, WordScreenError),
+}
+
+impl fmt::Display for LoadWordPacksError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadWordPacksError::Io(path, error) => {
+                write!(f, "could not read {}: {}", path.display(), error)
+            }
+            LoadWordPacksError::Invalid(path, error) => {
+                write!(f, "word pack {} {}", path.display(), error)
+            }
+            LoadWordPacksError::InvalidDictionary(path, error) => {
+                write!(f, "dictionary {} {}", path.display(), error)
+            }
+            LoadWordPacksError::InvalidScreen(path, kind, error) => write!(
+                f,
+                "{} {} {}",
+                match kind {
+                    ScreenKind::Block => "blocklist",
+                    ScreenKind::Allow => "allowlist",
+                },
+                path.display(),
+                error
+            ),
+        }
+    }
+}
+
+/// Loads the built-in word packs and any `.pack` files in the `packs` directory next to the
+/// config file, defined by the built-in dictionaries and any `.dict` files in the same directory,
+/// and screened as [`screen`] does. Returns the words with how many words each list removed.
+pub(crate) fn load_words(
+    family_friendly: bool,
+) -> Result<(WordList, Vec<(String, usize)>), LoadWordPacksError> {
+    let mut words = WordList::built_in().clone();
+
+    if let Some(directory) = packs_directory() {
+        let (pack_words, dictionary) = load_directory(&directory)?;
+
+        words.extend(pack_words);
+        words.define(&Dictionary::built_in());
+        words.define(&dictionary);
+    }
+
+    let removed = screen(&mut words, family_friendly)?;
+
+    Ok((words, removed))
+}
+
+/// Removes the words blocked by the family-friendly list, if it's turned on, and by any `.block`
+/// files in the `packs` directory, and the words missing from any `.allow` files there. Returns
+/// the name of each list with how many words it removed.
+pub(crate) fn screen(
+    words: &mut WordList,
+    family_friendly: bool,
+) -> Result<Vec<(String, usize)>, LoadWordPacksError> {
+    let mut screens = Vec::new();
+
+    if family_friendly {
+        screens.push(WordScreen::family_friendly());
+    }
+
+    if let Some(directory) = packs_directory() {
+        screens.extend(load_screens(&directory)?);
+    }
+
+    let removed = words.screen(&screens);
+
+    Ok(screens
+        .into_iter()
+        .map(|screen| screen.name)
+        .zip(removed)
+        .collect())
+}
+
+/// Loads every `.block` and `.allow` file in a directory in alphabetical order, each named after
+/// its file.
+fn load_screens(directory: &Path) -> Result<Vec<WordScreen>, LoadWordPacksError> {
+    let mut screens = Vec::new();
+
+    for path in files_in(directory, &[BLOCKLIST_EXTENSION, ALLOWLIST_EXTENSION])? {
+        let kind = match path.extension() {
+            Some(extension) if extension == BLOCKLIST_EXTENSION => ScreenKind::Block,
+            _ => ScreenKind::Allow,
+        };
+        let contents = fs::read_to_string(&path)
+            .map_err(|error| LoadWordPacksError::Io(path.clone(), error))?;
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        screens.push(
+            WordScreen::parse(&name, kind, &contents)
+                .map_err(|error| LoadWordPacksError::InvalidScreen(path.clone(), kind, error))?,
+        );
+    }
+
+    Ok(screens)
+}
+
+fn packs_directory() -> Option<PathBuf> {
+    Some(Config::path()?.parent()?.join(PACKS_DIRECTORY))
+}
+
+/// The `.pack` files in the `packs` directory next to the config file, in alphabetical order.
+pub(crate) fn pack_files() -> Result<Vec<PathBuf>, LoadWordPacksError> {
+    match packs_directory() {
+        Some(directory) => files_in(&directory, &[PACK_EXTENSION]),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// The files in a directory with one of the extensions in alphabetical order, or none if the
+/// directory doesn't exist.
+fn files_in(directory: &Path, extensions: &[&str]) -> Result<Vec<PathBuf>, LoadWordPacksError> {
+    let entries = match fs::read_dir(directory) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => return Err(LoadWordPacksError::Io(directory.to_path_buf(), error)),
+    };
+
+    let mut paths = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extensions.iter().any(|wanted| extension == *wanted))
+        })
+        .collect::<Vec<PathBuf>>();
+
+    paths.sort();
+
+    Ok(paths)
+}
+
+/// Reads the words of a word pack file.
+pub(crate) fn load_pack(path: &Path) -> Result<WordList, LoadWordPacksError> {
+    let contents = fs::read_to_string(path)
+        .map_err(|error| LoadWordPacksError::Io(path.to_path_buf(), error))?;
+
+    WordList::parse(&contents)
+        .map_err(|error| LoadWordPacksError::Invalid(path.to_path_buf(), error))
+}
+
+/// Loads every `.pack` and `.dict` file in a directory in alphabetical order, or nothing if the
+/// directory doesn't exist.
+fn load_directory(directory: &Path) -> Result<(WordList, Dictionary), LoadWordPacksError> {
+    let mut words = WordList::default();
+    let mut dictionary = Dictionary::default();
+
+    for path in files_in(directory, &[PACK_EXTENSION, DICTIONARY_EXTENSION])? {
+        if path
+            .extension()
+            .is_some_and(|extension| extension == PACK_EXTENSION)
+        {
+            words.extend(load_pack(&path)?);
+        } else {
+            let contents = fs::read_to_string(&path)
+                .map_err(|error| LoadWordPacksError::Io(path.clone(), error))?;
+
+            dictionary.extend(
+                Dictionary::parse(&contents)
+                    .map_err(|error| LoadWordPacksError::InvalidDictionary(path.clone(), error))?,
+            );
+        }
+    }
+
+    Ok((words, dictionary))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, process};
+
+    #[test]
+    fn packs_in_the_directory_are_loaded_and_errors_name_the_file_and_line() {
+        let directory = env::temp_dir().join(format!("hangman-packs-{}", process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        fs::write(
+            directory.join("animals.pack"),
+            "@language en\n@category animals\ncat\n",
+        )
+        .unwrap();
+        fs::write(
+            directory.join("animals.dict"),
+            "@language en\ncat | noun | A small furry pet.\n",
+        )
+        .unwrap();
+        fs::write(directory.join("notes.txt"), "not a pack").unwrap();
+
+        let (words, dictionary) = load_directory(&directory).unwrap();
+
+        fs::write(directory.join("broken.pack"), "@language en\n\ndog\n").unwrap();
+        let error = load_directory(&directory).unwrap_err();
+
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(words.categories(None), ["animals"]);
+        assert_eq!(dictionary.len(), 1);
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "word pack {} line 3: the word needs an \"@category\" line above it",
+                directory.join("broken.pack").display()
+            )
+        );
+        assert!(load_directory(&directory).unwrap().0.entries().is_empty());
+    }
+
+    #[test]
+    fn blocklists_and_allowlists_in_the_directory_are_loaded_in_order() {
+        let directory = env::temp_dir().join(format!("hangman-screens-{}", process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        fs::write(directory.join("b.allow"), "cat\nhorse\n").unwrap();
+        fs::write(directory.join("a.block"), "# No pets\ncat\n").unwrap();
+
+        let screens = load_screens(&directory).unwrap();
+
+        fs::write(directory.join("c.block"), "sea lion\n").unwrap();
+        let error = load_screens(&directory).unwrap_err();
+
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(
+            screens
+                .iter()
+                .map(|screen| (screen.name.as_str(), screen.kind))
+                .collect::<Vec<(&str, ScreenKind)>>(),
+            [
+                ("a.block", ScreenKind::Block),
+                ("b.allow", ScreenKind::Allow)
+            ]
+        );
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "blocklist {} line 1: the word contained \" \" but may only contain the letters \
+                 A to Z",
+                directory.join("c.block").display()
+            )
+        );
+    }
+}
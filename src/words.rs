@@ -0,0 +1,565 @@
+//! Word lists to pick the word for a [`Game`](crate::Game) from, loaded from word packs.
+//!
+//! A word pack is a text file with one word per line. Each word takes the category, difficulty
+//! and language set by the closest `@category`, `@difficulty` and `@language` lines above it, and
+//! can be followed by how common it is and by `|` and a clue, as in `cat 52.5 | A small furry pet`.
+//! Blank lines and lines starting with `#` are skipped.
+//!
+//! Definitions are kept apart from the words in a [`Dictionary`], so that one dictionary can
+//! define the words of many packs. [`RecentWords`] keeps track of the words played lately, so
+//! they don't come up again too soon. [`lint`] finds every mistake in a word pack, and
+//! [`LetterFrequencies`] estimates how hard each word is. A [`WordScreen`] keeps unwanted words,
+//! such as offensive ones, out of a list. [`WordList::random_weighted_entry`] picks common words
+//! more often on easy and rare words on hard.
+//!
+//! ```
+//! use hangman::words::{Difficulty, WordFilter, WordList};
+//!
+//! let words = WordList::parse(
+//!     "@language en
+//!      @category animals
+//!      @difficulty easy
+//!      cat | A small furry pet that purrs
+//!      @difficulty hard
+//!      axolotl",
+//! )
+//! .expect("the pack should be valid");
+//!
+//! let filter = WordFilter::new().category("animals").minimum_length(4);
+//! let entry = words.random_entry(&filter).expect("a word should match");
+//!
+//! assert_eq!(entry.word, "AXOLOTL");
+//! assert_eq!(entry.difficulty, Difficulty::Hard);
+//! assert_eq!(entry.clue, None);
+//!
+//! let error = words.random_entry(&filter.maximum_length(5)).unwrap_err();
+//!
+//! assert_eq!(
+//!     error.to_string(),
+//!     "there are no words in the \"animals\" category with 4 to 5 letters"
+//! );
+//! ```
+
+use index::{letter_set, WordIndex};
+use rand::{seq::SliceRandom, thread_rng};
+use std::{error::Error, fmt, sync::OnceLock};
+
+pub use dictionary::{Definition, Dictionary, DictionaryError, DictionaryErrorKind};
+pub use lint::{lint, LintIssue, LintIssueKind, LintReport};
+pub use pack::{WordPackError, WordPackErrorKind};
+pub use recent::RecentWords;
+pub use screen::{ScreenKind, WordScreen, WordScreenError};
+pub use stats::LetterFrequencies;
+
+mod dictionary;
+mod index;
+mod lint;
+mod pack;
+mod recent;
+mod screen;
+mod stats;
+mod weighted;
+
+/// The word packs built into the game.
+const BUILT_IN_PACKS: [&str; 3] = [
+    include_str!("words/packs/rust.pack"),
+    include_str!("words/packs/animals.pack"),
+    include_str!("words/packs/food.pack"),
+];
+
+/// How hard a word is to guess.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Difficulty {
+    /// A short or common word.
+    Easy,
+    /// Most words.
+    #[default]
+    Medium,
+    /// A long or rare word, or one with unusual letters.
+    Hard,
+}
+
+impl Difficulty {
+    /// Every difficulty, from easiest to hardest.
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Medium, Difficulty::Hard];
+
+    /// The name used for the difficulty in word packs.
+    pub fn name(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "easy",
+            Difficulty::Medium => "medium",
+            Difficulty::Hard => "hard",
+        }
+    }
+
+    /// Reads a difficulty from its name, ignoring case.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|difficulty| difficulty.name().eq_ignore_ascii_case(name))
+    }
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// A word from a word pack and what the pack says about it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WordEntry {
+    /// The word, in uppercase.
+    pub word: String,
+    /// The category the word belongs to, in lowercase.
+    pub category: String,
+    /// How hard the word is to guess.
+    pub difficulty: Difficulty,
+    /// The code of the language the word is in, in lowercase, such as `en`.
+    pub language: String,
+    /// A clue or short definition of the word.
+    pub clue: Option<String>,
+    /// How common the word is, such as the number of times it's used in every million words. Only
+    /// compared with the frequencies of other words, to pick common or rare words more often.
+    pub frequency: Option<f64>,
+    /// What the word means, from a [`Dictionary`].
+    pub definition: Option<Definition>,
+}
+
+/// The words of one or more word packs.
+///
+/// The words are indexed by length, letter set, language and category the first time they are
+/// filtered, so that picking from a large list stays quick.
+#[derive(Clone, Debug, Default)]
+pub struct WordList {
+    entries: Vec<WordEntry>,
+    index: OnceLock<WordIndex>,
+}
+
+impl PartialEq for WordList {
+    fn eq(&self, other: &Self) -> bool {
+        self.entries == other.entries
+    }
+}
+
+impl WordList {
+    /// The words of the packs built into the game, in English and French, defined by the
+    /// built-in dictionaries. The packs are only read and indexed once, and the same list is
+    /// shared from then on.
+    pub fn built_in() -> &'static Self {
+        static BUILT_IN: OnceLock<WordList> = OnceLock::new();
+
+        BUILT_IN.get_or_init(|| {
+            let mut words = WordList::default();
+
+            for pack in BUILT_IN_PACKS {
+                words.extend(
+                    WordList::parse(pack).expect("the built-in word packs should be valid"),
+                );
+            }
+
+            words.define(&Dictionary::built_in());
+            words.index();
+
+            words
+        })
+    }
+
+    /// Every word in the list, in the order they were loaded.
+    pub fn entries(&self) -> &[WordEntry] {
+        &self.entries
+    }
+
+    /// Adds the words of another list, such as another word pack.
+    pub fn extend(&mut self, other: WordList) {
+        self.entries.extend(other.entries);
+        self.index = OnceLock::new();
+    }
+
+    /// Gives each word that is in a dictionary its definition, replacing any it already had.
+    pub fn define(&mut self, dictionary: &Dictionary) {
+        for entry in self.entries.iter_mut() {
+            if let Some(definition) = dictionary.lookup(&entry.word, &entry.language) {
+                entry.definition = Some(definition.clone());
+            }
+        }
+    }
+
+    /// The categories with words in a language, or in any language if `language` is `None`, in
+    /// alphabetical order.
+    pub fn categories(&self, language: Option<&str>) -> Vec<String> {
+        let mut categories = self
+            .entries
+            .iter()
+            .filter(|entry| language.is_none_or(|language| entry.language == language))
+            .map(|entry| entry.category.clone())
+            .collect::<Vec<String>>();
+
+        categories.sort();
+        categories.dedup();
+
+        categories
+    }
+
+    /// The words that match a filter, in the order they were loaded.
+    pub fn matching(&self, filter: &WordFilter) -> impl Iterator<Item = &WordEntry> + '_ {
+        self.index()
+            .matching(&self.entries, filter)
+            .into_iter()
+            .map(|position| &self.entries[position])
+    }
+
+    /// Picks a random word that matches a filter.
+    pub fn random_entry(&self, filter: &WordFilter) -> Result<&WordEntry, PickWordError> {
+        self.index()
+            .matching(&self.entries, filter)
+            .choose(&mut thread_rng())
+            .map(|position| &self.entries[*position])
+            .ok_or_else(|| self.pick_error(filter))
+    }
+
+    /// Why no word could be picked with a filter.
+    pub(crate) fn pick_error(&self, filter: &WordFilter) -> PickWordError {
+        if self.entries.is_empty() {
+            PickWordError::NoWords
+        } else {
+            PickWordError::NoMatchingWords(filter.clone())
+        }
+    }
+
+    fn index(&self) -> &WordIndex {
+        self.index.get_or_init(|| WordIndex::new(&self.entries))
+    }
+}
+
+/// Reads a two or three letter language code, such as `en`, in lowercase.
+fn parse_language(value: &str) -> Option<String> {
+    if (2..=3).contains(&value.len()) && value.chars().all(|char| char.is_ascii_alphabetic()) {
+        Some(value.to_lowercase())
+    } else {
+        None
+    }
+}
+
+/// Which words of a [`WordList`] can be picked. Every word matches a new filter.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WordFilter {
+    category: Option<String>,
+    language: Option<String>,
+    minimum_length: usize,
+    maximum_length: Option<usize>,
+    unique_letters: Option<usize>,
+    excluded_letters: String,
+}
+
+impl WordFilter {
+    /// Starts a filter that every word matches.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only matches words in a category, ignoring case.
+    pub fn category(mut self, category: &str) -> Self {
+        self.category = Some(category.to_lowercase());
+        self
+    }
+
+    /// Only matches words in a language, given by its code such as `en`.
+    pub fn language(mut self, language: &str) -> Self {
+        self.language = Some(language.to_lowercase());
+        self
+    }
+
+    /// Only matches words with at least this many letters.
+    pub fn minimum_length(mut self, minimum_length: usize) -> Self {
+        self.minimum_length = minimum_length;
+        self
+    }
+
+    /// Only matches words with at most this many letters.
+    pub fn maximum_length(mut self, maximum_length: usize) -> Self {
+        self.maximum_length = Some(maximum_length);
+        self
+    }
+
+    /// Only matches words with exactly this many letters.
+    pub fn exact_length(self, length: usize) -> Self {
+        self.minimum_length(length).maximum_length(length)
+    }
+
+    /// Only matches words with exactly this many different letters, so `"banana"` has 3.
+    pub fn unique_letters(mut self, unique_letters: usize) -> Self {
+        self.unique_letters = Some(unique_letters);
+        self
+    }
+
+    /// Only matches words without any of these letters, ignoring case.
+    pub fn excluding_letters(mut self, letters: &str) -> Self {
+        self.excluded_letters = letters.to_uppercase();
+        self
+    }
+
+    /// The category words must be in, if any.
+    pub fn required_category(&self) -> Option<&str> {
+        self.category.as_deref()
+    }
+
+    /// The code of the language words must be in, if any.
+    pub fn required_language(&self) -> Option<&str> {
+        self.language.as_deref()
+    }
+
+    /// The fewest and, if there is a limit, the most letters words can have.
+    pub fn length_range(&self) -> (usize, Option<usize>) {
+        (self.minimum_length, self.maximum_length)
+    }
+
+    /// The number of different letters words must have, if any.
+    pub fn required_unique_letters(&self) -> Option<usize> {
+        self.unique_letters
+    }
+
+    /// The letters words can't have, in uppercase.
+    pub fn excluded_letters(&self) -> &str {
+        &self.excluded_letters
+    }
+
+    /// Whether a word matches the filter.
+    pub fn matches(&self, entry: &WordEntry) -> bool {
+        self.category
+            .as_ref()
+            .is_none_or(|category| entry.category == *category)
+            && self
+                .language
+                .as_ref()
+                .is_none_or(|language| entry.language == *language)
+            && self.allows_length(entry.word.chars().count())
+            && self.allows_letters(letter_set(&entry.word))
+    }
+
+    fn allows_length(&self, length: usize) -> bool {
+        length >= self.minimum_length && self.maximum_length.is_none_or(|maximum| length <= maximum)
+    }
+
+    fn allows_letters(&self, letters: u32) -> bool {
+        letters & letter_set(&self.excluded_letters) == 0
+            && self
+                .unique_letters
+                .is_none_or(|unique_letters| letters.count_ones() as usize == unique_letters)
+    }
+}
+
+/// Describes the words a filter matches in English, such as `words in "en" with at least 4
+/// letters`. The getters give what's needed to describe it in other languages.
+impl fmt::Display for WordFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "words")?;
+
+        if let Some(language) = &self.language {
+            write!(f, " in \"{}\"", language)?;
+        }
+
+        if let Some(category) = &self.category {
+            write!(f, " in the \"{}\" category", category)?;
+        }
+
+        match self.maximum_length {
+            Some(maximum) if maximum == self.minimum_length => {
+                write!(f, " with {} letters", maximum)?
+            }
+            Some(maximum) => write!(f, " with {} to {} letters", self.minimum_length, maximum)?,
+            None if self.minimum_length > 1 => {
+                write!(f, " with at least {} letters", self.minimum_length)?
+            }
+            None => {}
+        }
+
+        if let Some(unique_letters) = self.unique_letters {
+            write!(f, " and {} different letters", unique_letters)?;
+        }
+
+        if !self.excluded_letters.is_empty() {
+            write!(f, " without the letters {}", self.excluded_letters)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A reason no word could be picked from a [`WordList`].
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum PickWordError {
+    /// The list doesn't have any words.
+    NoWords,
+    /// The list has words, but none of them match the filter.
+    NoMatchingWords(WordFilter),
+    /// The words' weights at a [`Difficulty`] are too large or small to pick by.
+    InvalidWeights(Difficulty),
+}
+
+impl fmt::Display for PickWordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PickWordError::NoWords => write!(f, "there are no words to pick from"),
+            PickWordError::NoMatchingWords(filter) => write!(f, "there are no {}", filter),
+            PickWordError::InvalidWeights(difficulty) => write!(
+                f,
+                "the word frequencies are too large or small to pick {} words by",
+                difficulty
+            ),
+        }
+    }
+}
+
+impl Error for PickWordError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_built_in_packs_have_words_in_every_supported_language() {
+        let words = WordList::built_in();
+
+        for language in ["en", "fr"] {
+            assert!(!words.categories(Some(language)).is_empty());
+            assert!(words
+                .random_entry(&WordFilter::new().language(language).minimum_length(4))
+                .is_ok());
+        }
+    }
+
+    #[test]
+    fn the_built_in_words_are_loaded_once_and_shared() {
+        assert!(std::ptr::eq(WordList::built_in(), WordList::built_in()));
+    }
+
+    #[test]
+    fn every_built_in_word_has_a_definition() {
+        let undefined = WordList::built_in()
+            .entries()
+            .iter()
+            .filter(|entry| entry.definition.is_none())
+            .map(|entry| entry.word.clone())
+            .collect::<Vec<String>>();
+
+        assert_eq!(undefined, Vec::<String>::new());
+    }
+
+    #[test]
+    fn categories_are_listed_once_in_alphabetical_order() {
+        let words = WordList::parse(
+            "@language en\n@category food\napple\n@category animals\ncat\ndog\n\
+             @language fr\n@category animaux\nchat\n",
+        )
+        .unwrap();
+
+        assert_eq!(words.categories(None), ["animals", "animaux", "food"]);
+        assert_eq!(words.categories(Some("en")), ["animals", "food"]);
+    }
+
+    #[test]
+    fn only_words_matching_every_part_of_the_filter_are_picked() {
+        let words = WordList::parse(
+            "@language en\n@category animals\ncat\nhorse\n@category food\ncheese\n\
+             @language fr\n@category animals\ncheval\n",
+        )
+        .unwrap();
+        let filter = WordFilter::new()
+            .category("Animals")
+            .language("en")
+            .minimum_length(4);
+
+        assert_eq!(
+            words
+                .matching(&filter)
+                .map(|entry| entry.word.as_str())
+                .collect::<Vec<&str>>(),
+            ["HORSE"]
+        );
+        assert_eq!(
+            words.random_entry(&WordFilter::new().category("sport")),
+            Err(PickWordError::NoMatchingWords(
+                WordFilter::new().category("sport")
+            ))
+        );
+    }
+
+    #[test]
+    fn picking_from_an_empty_or_fully_filtered_list_returns_a_descriptive_error() {
+        let words = WordList::parse("@language en\n@category animals\ncat\nhorse\n").unwrap();
+        let filter = WordFilter::new()
+            .language("en")
+            .minimum_length(6)
+            .unique_letters(5)
+            .excluding_letters("xz");
+
+        assert_eq!(
+            WordList::default().random_entry(&WordFilter::new()),
+            Err(PickWordError::NoWords)
+        );
+        assert_eq!(
+            WordList::default()
+                .random_entry(&WordFilter::new())
+                .unwrap_err()
+                .to_string(),
+            "there are no words to pick from"
+        );
+        assert_eq!(
+            words.random_entry(&filter).unwrap_err().to_string(),
+            "there are no words in \"en\" with at least 6 letters and 5 different letters \
+             without the letters XZ"
+        );
+        assert_eq!(
+            words
+                .random_entry(&WordFilter::new().exact_length(4))
+                .unwrap_err()
+                .to_string(),
+            "there are no words with 4 letters"
+        );
+    }
+
+    #[test]
+    fn words_can_be_picked_by_maximum_exact_and_unique_letter_length() {
+        let words = WordList::parse(
+            "@language en
+@category animals
+cat
+llama
+horse
+kangaroo
+@category food
+banana
+",
+        )
+        .unwrap();
+        let picked = |filter: WordFilter| {
+            words
+                .matching(&filter)
+                .map(|entry| entry.word.as_str())
+                .collect::<Vec<&str>>()
+        };
+
+        assert_eq!(
+            picked(WordFilter::new().maximum_length(5)),
+            ["CAT", "LLAMA", "HORSE"]
+        );
+        assert_eq!(
+            picked(WordFilter::new().exact_length(5)),
+            ["LLAMA", "HORSE"]
+        );
+        assert_eq!(
+            picked(WordFilter::new().unique_letters(3)),
+            ["CAT", "LLAMA", "BANANA"]
+        );
+        assert_eq!(
+            picked(WordFilter::new().minimum_length(4).unique_letters(3)),
+            ["LLAMA", "BANANA"]
+        );
+        assert_eq!(
+            picked(WordFilter::new().minimum_length(6).maximum_length(5)),
+            Vec::<&str>::new()
+        );
+    }
+}
@@ -0,0 +1,344 @@
+use crate::{
+    game::{parse_word, InvalidWordError},
+    words::parse_language,
+};
+use std::{collections::HashMap, error::Error, fmt};
+
+/// The dictionaries built into the game.
+const BUILT_IN_DICTIONARIES: [&str; 2] = [
+    include_str!("dictionaries/en.dict"),
+    include_str!("dictionaries/fr.dict"),
+];
+
+/// What a word means.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Definition {
+    /// The part of speech, such as `noun` or `verb`, in the language of the word.
+    pub part_of_speech: String,
+    /// The meaning of the word.
+    pub definition: String,
+    /// A sentence using the word.
+    pub example: Option<String>,
+}
+
+/// Definitions of words, read from dictionary files.
+///
+/// A dictionary file has one word per line, followed by its part of speech, its definition and
+/// optionally an example sentence, separated by `|`. Each word is in the language set by the
+/// closest `@language` line above it. Blank lines and lines starting with `#` are skipped.
+///
+/// ```
+/// use hangman::words::Dictionary;
+///
+/// let dictionary = Dictionary::parse(
+///     "@language en
+///      crate | noun | A package of Rust code. | Add the crate to Cargo.toml.",
+/// )
+/// .expect("the dictionary should be valid");
+///
+/// let definition = dictionary.lookup("Crate", "en").expect("crate should be defined");
+///
+/// assert_eq!(definition.part_of_speech, "noun");
+/// assert_eq!(dictionary.lookup("crate", "fr"), None);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Dictionary {
+    definitions: HashMap<(String, String), Definition>,
+}
+
+/// A line of a dictionary file that couldn't be read.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DictionaryError {
+    /// The line the error is on, counting from 1.
+    pub line: usize,
+    /// What is wrong with the line.
+    pub kind: DictionaryErrorKind,
+}
+
+/// What is wrong with a line of a dictionary file.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum DictionaryErrorKind {
+    /// A line started with `@` but wasn't `@language`.
+    UnknownDirective(String),
+    /// `@language` was given without a value.
+    MissingValue(String),
+    /// `@language` wasn't a two or three letter language code.
+    InvalidLanguage(String),
+    /// A word can't be played with.
+    InvalidWord(InvalidWordError),
+    /// A word was missing its part of speech or its definition, or had an empty example.
+    MissingField(&'static str),
+    /// A word was followed by more than an example sentence.
+    TooManyFields,
+    /// A word came before any `@language` line.
+    NoLanguage,
+    /// A word was defined twice in the same language.
+    DuplicateWord {
+        /// The word, in uppercase.
+        word: String,
+        /// The line the word was first defined on.
+        first_line: usize,
+    },
+}
+
+impl fmt::Display for DictionaryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+
+        match &self.kind {
+            DictionaryErrorKind::UnknownDirective(directive) => write!(
+                f,
+                "unknown directive \"@{}\", expected @language",
+                directive
+            ),
+            DictionaryErrorKind::MissingValue(directive) => {
+                write!(f, "\"@{}\" needs a value", directive)
+            }
+            DictionaryErrorKind::InvalidLanguage(language) => write!(
+                f,
+                "language must be a two or three letter code such as \"en\" but was \"{}\"",
+                language
+            ),
+            DictionaryErrorKind::InvalidWord(error) => write!(f, "{}", error),
+            DictionaryErrorKind::MissingField(field) => {
+                write!(f, "the word is missing its {}", field)
+            }
+            DictionaryErrorKind::TooManyFields => write!(
+                f,
+                "expected \"word | part of speech | definition | example\" but found more"
+            ),
+            DictionaryErrorKind::NoLanguage => {
+                write!(f, "the word needs an \"@language\" line above it")
+            }
+            DictionaryErrorKind::DuplicateWord { word, first_line } => {
+                write!(f, "\"{}\" is already defined on line {}", word, first_line)
+            }
+        }
+    }
+}
+
+impl Error for DictionaryError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            DictionaryErrorKind::InvalidWord(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl Dictionary {
+    /// The definitions of the words in the built-in word packs.
+    pub fn built_in() -> Self {
+        let mut dictionary = Dictionary::default();
+
+        for contents in BUILT_IN_DICTIONARIES {
+            dictionary.extend(
+                Dictionary::parse(contents).expect("the built-in dictionaries should be valid"),
+            );
+        }
+
+        dictionary
+    }
+
+    /// Reads a dictionary file, stopping at the first line that isn't valid.
+    pub fn parse(contents: &str) -> Result<Self, DictionaryError> {
+        let mut definitions = HashMap::new();
+        let mut first_lines = HashMap::new();
+        let mut language = None;
+
+        for (index, line) in contents.lines().enumerate() {
+            let line_number = index + 1;
+            let error = |kind| DictionaryError {
+                line: line_number,
+                kind,
+            };
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(directive) = line.strip_prefix('@') {
+                let (name, value) = directive
+                    .split_once(char::is_whitespace)
+                    .map(|(name, value)| (name, value.trim()))
+                    .unwrap_or((directive, ""));
+                let name = name.to_lowercase();
+
+                if name != "language" {
+                    return Err(error(DictionaryErrorKind::UnknownDirective(name)));
+                }
+
+                if value.is_empty() {
+                    return Err(error(DictionaryErrorKind::MissingValue(name)));
+                }
+
+                language = Some(parse_language(value).ok_or_else(|| {
+                    error(DictionaryErrorKind::InvalidLanguage(value.to_string()))
+                })?);
+
+                continue;
+            }
+
+            let fields = line.split('|').map(str::trim).collect::<Vec<&str>>();
+
+            let (word, part_of_speech, definition, example) = match fields.as_slice() {
+                [word, part_of_speech, definition] => (word, part_of_speech, definition, None),
+                [word, part_of_speech, definition, example] => {
+                    (word, part_of_speech, definition, Some(example))
+                }
+                [_] => return Err(error(DictionaryErrorKind::MissingField("part of speech"))),
+                [_, _] => return Err(error(DictionaryErrorKind::MissingField("definition"))),
+                _ => return Err(error(DictionaryErrorKind::TooManyFields)),
+            };
+
+            if part_of_speech.is_empty() {
+                return Err(error(DictionaryErrorKind::MissingField("part of speech")));
+            }
+
+            if definition.is_empty() {
+                return Err(error(DictionaryErrorKind::MissingField("definition")));
+            }
+
+            if example.is_some_and(|example| example.is_empty()) {
+                return Err(error(DictionaryErrorKind::MissingField("example")));
+            }
+
+            let word = parse_word(word, false)
+                .map_err(|invalid_word| error(DictionaryErrorKind::InvalidWord(invalid_word)))?;
+            let language = language
+                .clone()
+                .ok_or_else(|| error(DictionaryErrorKind::NoLanguage))?;
+            let key = (language, word);
+
+            if let Some(&first_line) = first_lines.get(&key) {
+                return Err(error(DictionaryErrorKind::DuplicateWord {
+                    word: key.1,
+                    first_line,
+                }));
+            }
+
+            first_lines.insert(key.clone(), line_number);
+            definitions.insert(
+                key,
+                Definition {
+                    part_of_speech: part_of_speech.to_string(),
+                    definition: definition.to_string(),
+                    example: example.map(|example| example.to_string()),
+                },
+            );
+        }
+
+        Ok(Dictionary { definitions })
+    }
+
+    /// Adds the definitions of another dictionary, replacing any for the same words.
+    pub fn extend(&mut self, other: Dictionary) {
+        self.definitions.extend(other.definitions);
+    }
+
+    /// The definition of a word, ignoring case, in a language given by its code such as `en`.
+    pub fn lookup(&self, word: &str, language: &str) -> Option<&Definition> {
+        self.definitions
+            .get(&(language.to_lowercase(), word.trim().to_uppercase()))
+    }
+
+    /// The number of words with a definition.
+    pub fn len(&self) -> usize {
+        self.definitions.len()
+    }
+
+    /// Whether the dictionary has no definitions.
+    pub fn is_empty(&self) -> bool {
+        self.definitions.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::words::WordList;
+
+    fn parse_error(contents: &str) -> DictionaryError {
+        Dictionary::parse(contents).unwrap_err()
+    }
+
+    #[test]
+    fn definitions_are_looked_up_by_word_and_language_and_given_to_word_lists() {
+        let dictionary = Dictionary::parse(
+            "# Animals\n@language en\ncat | noun | A small furry pet. | The cat purred.\n\
+             @language fr\nchat | nom | Un petit animal.\n",
+        )
+        .unwrap();
+        let mut words =
+            WordList::parse("@category animals\n@language en\ncat\ndog\n@language fr\nchat\n")
+                .unwrap();
+
+        words.define(&dictionary);
+
+        assert_eq!(
+            dictionary.lookup("cat", "EN"),
+            Some(&Definition {
+                part_of_speech: String::from("noun"),
+                definition: String::from("A small furry pet."),
+                example: Some(String::from("The cat purred.")),
+            })
+        );
+        assert_eq!(
+            words
+                .entries()
+                .iter()
+                .map(|entry| entry
+                    .definition
+                    .as_ref()
+                    .map(|definition| &definition.definition))
+                .collect::<Vec<Option<&String>>>(),
+            [
+                Some(&String::from("A small furry pet.")),
+                None,
+                Some(&String::from("Un petit animal.")),
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_lines_return_an_error_with_the_line_number() {
+        let header = "@language en\n";
+
+        assert_eq!(
+            parse_error(&format!("{}\ncat\n", header)),
+            DictionaryError {
+                line: 3,
+                kind: DictionaryErrorKind::MissingField("part of speech")
+            }
+        );
+        assert_eq!(
+            parse_error(&format!("{}cat | noun |  \n", header)).kind,
+            DictionaryErrorKind::MissingField("definition")
+        );
+        assert_eq!(
+            parse_error(&format!("{}cat | noun | A pet. | Purr. | Meow.\n", header)).kind,
+            DictionaryErrorKind::TooManyFields
+        );
+        assert_eq!(
+            parse_error(&format!(
+                "{}cat | noun | A pet.\nCAT | verb | To vomit.\n",
+                header
+            ))
+            .kind,
+            DictionaryErrorKind::DuplicateWord {
+                word: String::from("CAT"),
+                first_line: 2
+            }
+        );
+        assert_eq!(
+            parse_error("cat | noun | A pet.\n").to_string(),
+            "line 1: the word needs an \"@language\" line above it"
+        );
+        assert_eq!(
+            parse_error("@category animals\n").kind,
+            DictionaryErrorKind::UnknownDirective(String::from("category"))
+        );
+    }
+}
@@ -0,0 +1,171 @@
+use crate::words::{WordEntry, WordFilter};
+use std::{
+    collections::{BTreeMap, HashMap},
+    ops::RangeInclusive,
+};
+
+/// Where the words of a [`WordList`](crate::words::WordList) are, by length, letter set,
+/// language and category, so that picking from a large list doesn't look at every word.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct WordIndex {
+    /// The number of letters in each word.
+    lengths: Vec<usize>,
+    /// The letters in each word, with A as the lowest bit.
+    letter_sets: Vec<u32>,
+    /// The positions of the words with each number of letters.
+    by_length: BTreeMap<usize, Vec<usize>>,
+    /// The positions of the words in each language.
+    by_language: HashMap<String, Vec<usize>>,
+    /// The positions of the words in each category.
+    by_category: HashMap<String, Vec<usize>>,
+}
+
+impl WordIndex {
+    pub(crate) fn new(entries: &[WordEntry]) -> Self {
+        let mut index = WordIndex::default();
+
+        for (position, entry) in entries.iter().enumerate() {
+            let length = entry.word.chars().count();
+
+            index.lengths.push(length);
+            index.letter_sets.push(letter_set(&entry.word));
+            index.by_length.entry(length).or_default().push(position);
+            add_position(&mut index.by_language, &entry.language, position);
+            add_position(&mut index.by_category, &entry.category, position);
+        }
+
+        index
+    }
+
+    /// The positions of the words that match a filter, in the order they were loaded.
+    pub(crate) fn matching(&self, entries: &[WordEntry], filter: &WordFilter) -> Vec<usize> {
+        let Some(lengths) = length_range(filter) else {
+            return Vec::new();
+        };
+        let matches = |position: &usize| {
+            lengths.contains(&self.lengths[*position])
+                && filter.allows_letters(self.letter_sets[*position])
+                && filter
+                    .language
+                    .as_ref()
+                    .is_none_or(|language| entries[*position].language == *language)
+                && filter
+                    .category
+                    .as_ref()
+                    .is_none_or(|category| entries[*position].category == *category)
+        };
+
+        // Only the words in the shortest of the lists the filter narrows down to are checked.
+        let narrowest = [
+            filter
+                .language
+                .as_ref()
+                .map(|language| positions_of(&self.by_language, language)),
+            filter
+                .category
+                .as_ref()
+                .map(|category| positions_of(&self.by_category, category)),
+        ]
+        .into_iter()
+        .flatten()
+        .min_by_key(|positions| positions.len());
+        let right_length = self
+            .by_length
+            .range(lengths.clone())
+            .map(|(_, positions)| positions.len())
+            .sum::<usize>();
+
+        match narrowest {
+            Some(positions) if positions.len() <= right_length => {
+                positions.iter().copied().filter(matches).collect()
+            }
+            _ if right_length == self.lengths.len() => {
+                (0..self.lengths.len()).filter(matches).collect()
+            }
+            _ => {
+                let mut positions = self
+                    .by_length
+                    .range(lengths.clone())
+                    .flat_map(|(_, positions)| positions.iter().copied())
+                    .filter(matches)
+                    .collect::<Vec<usize>>();
+
+                positions.sort_unstable();
+
+                positions
+            }
+        }
+    }
+}
+
+/// Adds a position to the list for a key, only copying the key the first time it's seen.
+fn add_position(lists: &mut HashMap<String, Vec<usize>>, key: &str, position: usize) {
+    match lists.get_mut(key) {
+        Some(positions) => positions.push(position),
+        None => {
+            lists.insert(key.to_string(), vec![position]);
+        }
+    }
+}
+
+/// The word lengths a filter allows, or `None` if its maximum is below its minimum.
+fn length_range(filter: &WordFilter) -> Option<RangeInclusive<usize>> {
+    let maximum = filter.maximum_length.unwrap_or(usize::MAX);
+
+    (filter.minimum_length <= maximum).then_some(filter.minimum_length..=maximum)
+}
+
+fn positions_of<'a>(lists: &'a HashMap<String, Vec<usize>>, key: &str) -> &'a [usize] {
+    lists.get(key).map_or(&[], Vec::as_slice)
+}
+
+/// The letters A to Z in a word as bits, with A as the lowest bit. Other characters are left out.
+pub(crate) fn letter_set(word: &str) -> u32 {
+    word.chars()
+        .filter(char::is_ascii_alphabetic)
+        .fold(0, |letters, char| {
+            letters | 1 << (char.to_ascii_uppercase() as u32 - 'A' as u32)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::words::WordList;
+
+    #[test]
+    fn letter_sets_have_a_bit_for_each_letter() {
+        assert_eq!(letter_set(""), 0);
+        assert_eq!(letter_set("Aa"), 0b1);
+        assert_eq!(letter_set("cab"), 0b111);
+        assert_eq!(letter_set("z-z"), 1 << 25);
+    }
+
+    #[test]
+    fn the_index_finds_the_same_words_as_checking_every_word() {
+        let words = WordList::parse(
+            "@language en\n@category animals\ncat\nhorse\nzebra\n@category food\ncheese\n\
+             @language fr\n@category animals\ncheval\n",
+        )
+        .unwrap();
+        let filters = [
+            WordFilter::new(),
+            WordFilter::new().language("en").minimum_length(4),
+            WordFilter::new().category("animals").excluding_letters("z"),
+            WordFilter::new().language("fr").category("food"),
+            WordFilter::new().minimum_length(20),
+            WordFilter::new().maximum_length(5).unique_letters(3),
+            WordFilter::new().language("en").exact_length(6),
+            WordFilter::new().minimum_length(6).maximum_length(5),
+        ];
+
+        for filter in filters {
+            assert_eq!(
+                WordIndex::new(words.entries()).matching(words.entries(), &filter),
+                (0..words.entries().len())
+                    .filter(|position| filter.matches(&words.entries()[*position]))
+                    .collect::<Vec<usize>>()
+            );
+        }
+    }
+}
@@ -0,0 +1,198 @@
+use crate::words::{pack::PackReader, WordPackError, WordPackErrorKind};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+};
+
+/// A problem with a line of a word pack, found by [`lint`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct LintIssue {
+    /// The line the problem is on, counting from 1.
+    pub line: usize,
+    /// What is wrong with the line.
+    pub kind: LintIssueKind,
+}
+
+/// What is wrong with a line of a word pack.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum LintIssueKind {
+    /// The line isn't valid, so loading the pack would fail.
+    Invalid(WordPackErrorKind),
+    /// The line starts or ends with spaces or tabs.
+    Whitespace,
+    /// A word is also in another category in the same language.
+    RepeatedWord {
+        /// The word, in uppercase.
+        word: String,
+        /// The category the word first appeared in.
+        category: String,
+        /// The line the word first appeared on.
+        first_line: usize,
+    },
+}
+
+impl fmt::Display for LintIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            LintIssueKind::Invalid(kind) => write!(
+                f,
+                "{}",
+                WordPackError {
+                    line: self.line,
+                    kind: kind.clone(),
+                }
+            ),
+            LintIssueKind::Whitespace => {
+                write!(f, "line {}: the line starts or ends with spaces", self.line)
+            }
+            LintIssueKind::RepeatedWord {
+                word,
+                category,
+                first_line,
+            } => write!(
+                f,
+                "line {}: \"{}\" is also in \"{}\" on line {}",
+                self.line, word, category, first_line
+            ),
+        }
+    }
+}
+
+/// What [`lint`] found in a word pack.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LintReport {
+    /// Every problem, in line order.
+    pub issues: Vec<LintIssue>,
+    /// The number of valid words with each number of letters.
+    pub lengths: BTreeMap<usize, usize>,
+}
+
+/// Checks every line of a word pack, carrying on past mistakes so that they can all be fixed at
+/// once, and counts the words of each length.
+///
+/// ```
+/// use hangman::words::{lint, LintIssueKind};
+///
+/// let report = lint("@language en\n@category animals\ncat \nsea lion\n");
+///
+/// assert_eq!(report.issues.len(), 2);
+/// assert_eq!(report.issues[0].kind, LintIssueKind::Whitespace);
+/// assert_eq!(
+///     report.issues[1].to_string(),
+///     "line 4: the word contained \" \" but may only contain the letters A to Z"
+/// );
+/// assert_eq!(report.lengths.get(&3), Some(&1));
+/// ```
+pub fn lint(contents: &str) -> LintReport {
+    let mut reader = PackReader::default();
+    let mut report = LintReport::default();
+    let mut first_lines = HashMap::<(String, String), (String, usize)>::new();
+
+    for (index, line) in contents.lines().enumerate() {
+        let line_number = index + 1;
+        let mut issue = |kind| {
+            report.issues.push(LintIssue {
+                line: line_number,
+                kind,
+            })
+        };
+
+        if line != line.trim() {
+            issue(LintIssueKind::Whitespace);
+        }
+
+        match reader.read_line(line_number, line) {
+            Ok(Some(entry)) => {
+                match first_lines.get(&(entry.word.clone(), entry.language.clone())) {
+                    Some((category, first_line)) if *category != entry.category => {
+                        issue(LintIssueKind::RepeatedWord {
+                            word: entry.word.clone(),
+                            category: category.clone(),
+                            first_line: *first_line,
+                        })
+                    }
+                    Some(_) => {}
+                    None => {
+                        first_lines.insert(
+                            (entry.word.clone(), entry.language.clone()),
+                            (entry.category.clone(), line_number),
+                        );
+                    }
+                }
+
+                *report
+                    .lengths
+                    .entry(entry.word.chars().count())
+                    .or_default() += 1;
+            }
+            Ok(None) => {}
+            Err(kind) => issue(LintIssueKind::Invalid(kind)),
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{words::BUILT_IN_PACKS, InvalidWordError};
+
+    #[test]
+    fn every_problem_is_reported_instead_of_only_the_first() {
+        let report = lint(
+            "@language en\n@category animals\ncat\n\tdog\ncat\nh0rse\n@colour blue\n\
+             @category pets\nDog\n",
+        );
+
+        assert_eq!(
+            report.issues,
+            [
+                LintIssue {
+                    line: 4,
+                    kind: LintIssueKind::Whitespace
+                },
+                LintIssue {
+                    line: 5,
+                    kind: LintIssueKind::Invalid(WordPackErrorKind::DuplicateWord {
+                        word: String::from("CAT"),
+                        first_line: 3
+                    })
+                },
+                LintIssue {
+                    line: 6,
+                    kind: LintIssueKind::Invalid(WordPackErrorKind::InvalidWord(
+                        InvalidWordError::InvalidCharacter('0')
+                    ))
+                },
+                LintIssue {
+                    line: 7,
+                    kind: LintIssueKind::Invalid(WordPackErrorKind::UnknownDirective(
+                        String::from("colour")
+                    ))
+                },
+                LintIssue {
+                    line: 9,
+                    kind: LintIssueKind::RepeatedWord {
+                        word: String::from("DOG"),
+                        category: String::from("animals"),
+                        first_line: 4
+                    }
+                },
+            ]
+        );
+        assert_eq!(report.lengths, BTreeMap::from([(3, 3)]));
+        assert_eq!(
+            report.issues[4].to_string(),
+            "line 9: \"DOG\" is also in \"animals\" on line 4"
+        );
+    }
+
+    #[test]
+    fn the_built_in_packs_have_no_problems() {
+        for pack in BUILT_IN_PACKS {
+            assert_eq!(lint(pack).issues, []);
+        }
+    }
+}
@@ -0,0 +1,355 @@
+use crate::{
+    game::{parse_word, InvalidWordError},
+    words::{parse_language, Difficulty, WordEntry, WordList},
+};
+use std::{collections::HashMap, error::Error, fmt, ops::RangeInclusive};
+
+/// The frequencies a word can have, kept small and large enough that the weights words are picked
+/// by, and their total, never overflow.
+const FREQUENCY_RANGE: RangeInclusive<f64> = 1e-9..=1e9;
+
+/// A line of a word pack that couldn't be read.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WordPackError {
+    /// The line the error is on, counting from 1.
+    pub line: usize,
+    /// What is wrong with the line.
+    pub kind: WordPackErrorKind,
+}
+
+/// What is wrong with a line of a word pack.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum WordPackErrorKind {
+    /// A line started with `@` but wasn't `@category`, `@difficulty` or `@language`.
+    UnknownDirective(String),
+    /// A directive, such as `@category`, was given without a value.
+    MissingValue(String),
+    /// `@difficulty` wasn't `easy`, `medium` or `hard`.
+    InvalidDifficulty(String),
+    /// `@language` wasn't a two or three letter language code.
+    InvalidLanguage(String),
+    /// A word can't be played with.
+    InvalidWord(InvalidWordError),
+    /// A word was followed by `|` but no clue.
+    EmptyClue,
+    /// A word's frequency wasn't a number from 1e-9 to 1e9.
+    InvalidFrequency(String),
+    /// A word came before any `@category` line.
+    NoCategory,
+    /// A word came before any `@language` line.
+    NoLanguage,
+    /// A word appeared twice with the same category and language.
+    DuplicateWord {
+        /// The word, in uppercase.
+        word: String,
+        /// The line the word first appeared on.
+        first_line: usize,
+    },
+}
+
+impl fmt::Display for WordPackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+
+        match &self.kind {
+            WordPackErrorKind::UnknownDirective(directive) => write!(
+                f,
+                "unknown directive \"@{}\", expected @category, @difficulty or @language",
+                directive
+            ),
+            WordPackErrorKind::MissingValue(directive) => {
+                write!(f, "\"@{}\" needs a value", directive)
+            }
+            WordPackErrorKind::InvalidDifficulty(difficulty) => write!(
+                f,
+                "difficulty must be easy, medium or hard but was \"{}\"",
+                difficulty
+            ),
+            WordPackErrorKind::InvalidLanguage(language) => write!(
+                f,
+                "language must be a two or three letter code such as \"en\" but was \"{}\"",
+                language
+            ),
+            WordPackErrorKind::InvalidWord(error) => write!(f, "{}", error),
+            WordPackErrorKind::EmptyClue => write!(f, "the word is followed by \"|\" but no clue"),
+            WordPackErrorKind::InvalidFrequency(frequency) => write!(
+                f,
+                "frequency must be a number from {:e} to {:e} but was \"{}\"",
+                FREQUENCY_RANGE.start(),
+                FREQUENCY_RANGE.end(),
+                frequency
+            ),
+            WordPackErrorKind::NoCategory => {
+                write!(f, "the word needs an \"@category\" line above it")
+            }
+            WordPackErrorKind::NoLanguage => {
+                write!(f, "the word needs an \"@language\" line above it")
+            }
+            WordPackErrorKind::DuplicateWord { word, first_line } => write!(
+                f,
+                "\"{}\" is already in this category on line {}",
+                word, first_line
+            ),
+        }
+    }
+}
+
+impl Error for WordPackError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            WordPackErrorKind::InvalidWord(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl WordList {
+    /// Reads the words of a word pack, stopping at the first line that isn't valid.
+    pub fn parse(contents: &str) -> Result<Self, WordPackError> {
+        let mut reader = PackReader::default();
+        let mut entries = Vec::new();
+
+        for (index, line) in contents.lines().enumerate() {
+            let line_number = index + 1;
+
+            if let Some(entry) =
+                reader
+                    .read_line(line_number, line)
+                    .map_err(|kind| WordPackError {
+                        line: line_number,
+                        kind,
+                    })?
+            {
+                entries.push(entry);
+            }
+        }
+
+        Ok(WordList {
+            entries,
+            ..WordList::default()
+        })
+    }
+}
+
+/// Reads a word pack a line at a time, keeping track of the directives above each word.
+#[derive(Debug, Default)]
+pub(crate) struct PackReader {
+    first_lines: HashMap<(String, String, String), usize>,
+    category: Option<String>,
+    difficulty: Difficulty,
+    language: Option<String>,
+}
+
+impl PackReader {
+    /// Reads a line, returning the word on it if there is one. A line that isn't valid leaves the
+    /// directives as they were.
+    pub(crate) fn read_line(
+        &mut self,
+        line_number: usize,
+        line: &str,
+    ) -> Result<Option<WordEntry>, WordPackErrorKind> {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            return Ok(None);
+        }
+
+        if let Some(directive) = line.strip_prefix('@') {
+            let (name, value) = directive
+                .split_once(char::is_whitespace)
+                .map(|(name, value)| (name, value.trim()))
+                .unwrap_or((directive, ""));
+            let name = name.to_lowercase();
+
+            if !["category", "difficulty", "language"].contains(&name.as_str()) {
+                return Err(WordPackErrorKind::UnknownDirective(name));
+            }
+
+            if value.is_empty() {
+                return Err(WordPackErrorKind::MissingValue(name));
+            }
+
+            match name.as_str() {
+                "category" => self.category = Some(value.to_lowercase()),
+                "difficulty" => {
+                    self.difficulty = Difficulty::from_name(value)
+                        .ok_or_else(|| WordPackErrorKind::InvalidDifficulty(value.to_string()))?
+                }
+                _ => {
+                    self.language = Some(
+                        parse_language(value)
+                            .ok_or_else(|| WordPackErrorKind::InvalidLanguage(value.to_string()))?,
+                    )
+                }
+            }
+
+            return Ok(None);
+        }
+
+        let (word, clue) = match line.split_once('|') {
+            Some((_, clue)) if clue.trim().is_empty() => return Err(WordPackErrorKind::EmptyClue),
+            Some((word, clue)) => (word, Some(clue.trim().to_string())),
+            None => (line, None),
+        };
+
+        // A number after the word is how common it is. Anything else is left in the word, so
+        // that it's reported as an invalid character.
+        let (word, frequency) = match word.trim().rsplit_once(char::is_whitespace) {
+            Some((start, frequency)) => match frequency.parse::<f64>() {
+                Ok(number) if FREQUENCY_RANGE.contains(&number) => (start, Some(number)),
+                Ok(_) => return Err(WordPackErrorKind::InvalidFrequency(frequency.to_string())),
+                Err(_) => (word, None),
+            },
+            None => (word, None),
+        };
+
+        let word = parse_word(word, false).map_err(WordPackErrorKind::InvalidWord)?;
+        let category = self.category.clone().ok_or(WordPackErrorKind::NoCategory)?;
+        let language = self.language.clone().ok_or(WordPackErrorKind::NoLanguage)?;
+
+        if let Some(&first_line) =
+            self.first_lines
+                .get(&(word.clone(), category.clone(), language.clone()))
+        {
+            return Err(WordPackErrorKind::DuplicateWord { word, first_line });
+        }
+
+        self.first_lines.insert(
+            (word.clone(), category.clone(), language.clone()),
+            line_number,
+        );
+
+        Ok(Some(WordEntry {
+            word,
+            category,
+            difficulty: self.difficulty,
+            language,
+            clue,
+            frequency,
+            definition: None,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_error(contents: &str) -> WordPackError {
+        WordList::parse(contents).unwrap_err()
+    }
+
+    #[test]
+    fn each_word_takes_the_directives_above_it_and_an_optional_clue() {
+        let words = WordList::parse(
+            "# Animals\n@language EN\n@category Animals\n\ncat | A small furry pet\n\
+             @difficulty hard\n  Axolotl  \n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            words.entries(),
+            [
+                WordEntry {
+                    word: String::from("CAT"),
+                    category: String::from("animals"),
+                    difficulty: Difficulty::Medium,
+                    language: String::from("en"),
+                    clue: Some(String::from("A small furry pet")),
+                    frequency: None,
+                    definition: None,
+                },
+                WordEntry {
+                    word: String::from("AXOLOTL"),
+                    category: String::from("animals"),
+                    difficulty: Difficulty::Hard,
+                    language: String::from("en"),
+                    clue: None,
+                    frequency: None,
+                    definition: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn words_can_have_a_frequency_before_the_clue() {
+        let words = WordList::parse(
+            "@language en\n@category animals\ncat 52.5 | A small furry pet\nokapi\t0.2\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            words
+                .entries()
+                .iter()
+                .map(|entry| (entry.word.as_str(), entry.frequency, entry.clue.is_some()))
+                .collect::<Vec<(&str, Option<f64>, bool)>>(),
+            [("CAT", Some(52.5), true), ("OKAPI", Some(0.2), false)]
+        );
+    }
+
+    #[test]
+    fn invalid_directives_return_an_error_with_the_line_number() {
+        assert_eq!(
+            parse_error("@language en\n\n@colour blue\n"),
+            WordPackError {
+                line: 3,
+                kind: WordPackErrorKind::UnknownDirective(String::from("colour"))
+            }
+        );
+        assert_eq!(
+            parse_error("@category\n").kind,
+            WordPackErrorKind::MissingValue(String::from("category"))
+        );
+        assert_eq!(
+            parse_error("@difficulty tricky\n").kind,
+            WordPackErrorKind::InvalidDifficulty(String::from("tricky"))
+        );
+        assert_eq!(
+            parse_error("@language english\n").kind,
+            WordPackErrorKind::InvalidLanguage(String::from("english"))
+        );
+    }
+
+    #[test]
+    fn invalid_words_return_an_error_with_the_line_number() {
+        let header = "@language en\n@category animals\n";
+
+        assert_eq!(
+            parse_error(&format!("{}cat\nsea lion\n", header)),
+            WordPackError {
+                line: 4,
+                kind: WordPackErrorKind::InvalidWord(InvalidWordError::InvalidCharacter(' '))
+            }
+        );
+        assert_eq!(
+            parse_error(&format!("{}cat |  \n", header)).kind,
+            WordPackErrorKind::EmptyClue
+        );
+        assert_eq!(
+            parse_error(&format!("{}cat 0 | A small furry pet\n", header)).to_string(),
+            "line 3: frequency must be a number from 1e-9 to 1e9 but was \"0\""
+        );
+        assert_eq!(
+            parse_error(&format!("{}okapi 1e-310\n", header)).kind,
+            WordPackErrorKind::InvalidFrequency(String::from("1e-310"))
+        );
+        assert_eq!(
+            parse_error(&format!("{}cat\ndog\nCat\n", header)).kind,
+            WordPackErrorKind::DuplicateWord {
+                word: String::from("CAT"),
+                first_line: 3
+            }
+        );
+        assert_eq!(
+            parse_error("@language en\ncat\n").kind,
+            WordPackErrorKind::NoCategory
+        );
+        assert_eq!(
+            parse_error("@category animals\ncat\n").to_string(),
+            "line 2: the word needs an \"@language\" line above it"
+        );
+    }
+}
@@ -0,0 +1,217 @@
+use crate::words::{
+    weighted::choose_weighted, Difficulty, PickWordError, WordEntry, WordFilter, WordList,
+};
+use rand::thread_rng;
+use std::collections::HashSet;
+
+/// The most words kept, so that a long history doesn't grow without limit.
+const CAPACITY: usize = 1_000;
+
+/// The words played most recently, oldest first, so that they can be left out when picking the
+/// next word.
+///
+/// ```
+/// use hangman::words::{Difficulty, RecentWords, WordFilter, WordList};
+///
+/// let words = WordList::parse("@language en\n@category animals\ncat\ndog\n")
+///     .expect("the pack should be valid");
+/// let mut recent = RecentWords::new();
+///
+/// recent.record("en", "dog");
+///
+/// let entry = words
+///     .random_fresh_entry(&WordFilter::new(), &recent, 0.5, Difficulty::Medium)
+///     .expect("a word should match");
+///
+/// assert_eq!(entry.word, "CAT");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RecentWords {
+    words: Vec<(String, String)>,
+}
+
+impl RecentWords {
+    /// Starts a history without any words.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a word as the most recent one, given with the code of its language such as `en`.
+    /// Only the last 1,000 words are kept.
+    pub fn record(&mut self, language: &str, word: &str) {
+        let key = (language.to_lowercase(), word.to_uppercase());
+
+        self.words.retain(|recent| *recent != key);
+        self.words.push(key);
+
+        if self.words.len() > CAPACITY {
+            self.words.drain(..self.words.len() - CAPACITY);
+        }
+    }
+
+    /// The language and word of each word, oldest first.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (&str, &str)> {
+        self.words
+            .iter()
+            .map(|(language, word)| (language.as_str(), word.as_str()))
+    }
+
+    /// The number of words.
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Whether there aren't any words.
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Forgets every word, so that any word can be picked again.
+    pub fn clear(&mut self) {
+        self.words.clear();
+    }
+}
+
+impl WordList {
+    /// Picks a random word that matches a filter. Recent words are left out until `repeat_after`
+    /// (from 0 to 1) of the matching words have been played since, so with `0.5` a word can't come
+    /// up again until half of the other words have. The other words are as likely as their
+    /// [`Difficulty::weight`].
+    ///
+    /// Only the last 1,000 words are kept in [`RecentWords`], so at most 1,000 words are left out.
+    /// With more than 2,000 matching words and `0.5`, a word can come up again once 1,000 others
+    /// have been played.
+    pub fn random_fresh_entry(
+        &self,
+        filter: &WordFilter,
+        recent: &RecentWords,
+        repeat_after: f64,
+        difficulty: Difficulty,
+    ) -> Result<&WordEntry, PickWordError> {
+        let matching = self.matching(filter).collect::<Vec<&WordEntry>>();
+        let pool = matching
+            .iter()
+            .map(|entry| (entry.language.as_str(), entry.word.as_str()))
+            .collect::<HashSet<(&str, &str)>>();
+
+        // At least one word is always left to pick from, and no more are left out than are kept.
+        let limit = ((pool.len() as f64 * repeat_after.clamp(0.0, 1.0)) as usize)
+            .min(pool.len().saturating_sub(1))
+            .min(CAPACITY);
+        let left_out = recent
+            .iter()
+            .rev()
+            .filter(|word| pool.contains(word))
+            .take(limit)
+            .collect::<HashSet<(&str, &str)>>();
+
+        let fresh = matching
+            .into_iter()
+            .filter(|entry| !left_out.contains(&(entry.language.as_str(), entry.word.as_str())))
+            .collect::<Vec<&WordEntry>>();
+
+        choose_weighted(&fresh, difficulty, &mut thread_rng())?
+            .ok_or_else(|| self.pick_error(filter))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_the_most_recent_words_are_kept_once_each() {
+        let mut recent = RecentWords::new();
+
+        recent.record("EN", "cat");
+        recent.record("en", "dog");
+        recent.record("en", "CAT");
+
+        assert_eq!(
+            recent.iter().collect::<Vec<(&str, &str)>>(),
+            [("en", "DOG"), ("en", "CAT")]
+        );
+
+        for number in 0..CAPACITY {
+            recent.record("en", &number.to_string());
+        }
+
+        assert_eq!(recent.len(), CAPACITY);
+        assert_eq!(recent.iter().next(), Some(("en", "0")));
+
+        recent.clear();
+        assert!(recent.is_empty());
+    }
+
+    #[test]
+    fn recent_words_are_left_out_until_enough_of_the_pool_has_been_played() {
+        let words =
+            WordList::parse("@language en\n@category animals\ncat\ndog\nhorse\nmouse\n").unwrap();
+        let filter = WordFilter::new();
+        let mut recent = RecentWords::new();
+
+        for _ in 0..100 {
+            let word = words
+                .random_fresh_entry(&filter, &recent, 0.5, Difficulty::Medium)
+                .unwrap()
+                .word
+                .clone();
+
+            assert!(!recent
+                .iter()
+                .rev()
+                .take(2)
+                .any(|(_, recent)| recent == word));
+            recent.record("en", &word);
+        }
+
+        for word in ["cat", "dog", "horse"] {
+            recent.record("en", word);
+        }
+
+        assert_eq!(
+            words
+                .random_fresh_entry(&filter, &recent, 1.0, Difficulty::Medium)
+                .map(|entry| entry.word.as_str()),
+            Ok("MOUSE")
+        );
+        assert!(words
+            .random_fresh_entry(&filter, &recent, 0.0, Difficulty::Medium)
+            .is_ok());
+    }
+
+    #[test]
+    fn no_more_words_are_left_out_than_the_history_keeps() {
+        // The three letter words "aaa", "aab" and so on, counting in base 26.
+        let pool = (0..2_500)
+            .map(|number: usize| {
+                [number / 676, number / 26 % 26, number % 26]
+                    .map(|digit| char::from(b'a' + digit as u8))
+                    .iter()
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>();
+        let words = WordList::parse(&format!(
+            "@language en\n@category letters\n{}\n",
+            pool.join("\n")
+        ))
+        .unwrap();
+        let mut recent = RecentWords::new();
+
+        for word in &pool {
+            recent.record("en", word);
+        }
+
+        assert_eq!(recent.len(), CAPACITY);
+
+        // Half the pool would be 1,250 words, but only the last 1,000 are known to be recent.
+        for _ in 0..100 {
+            let word = &words
+                .random_fresh_entry(&WordFilter::new(), &recent, 0.5, Difficulty::Medium)
+                .unwrap()
+                .word;
+
+            assert!(pool[..pool.len() - CAPACITY].contains(&word.to_lowercase()));
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use crate::{
+    game::{parse_word, InvalidWordError},
+    words::WordList,
+};
+use std::{collections::HashSet, error::Error, fmt, sync::OnceLock};
+
+/// The words blocked by [`WordScreen::family_friendly`].
+const FAMILY_FRIENDLY_BLOCKLIST: &str = include_str!("screens/family_friendly.block");
+
+/// Whether a [`WordScreen`] removes its words or keeps only its words.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ScreenKind {
+    /// The words are removed, such as offensive words.
+    Block,
+    /// The words are the only ones kept, such as a list of approved words.
+    Allow,
+}
+
+/// A blocklist or allowlist of words, applied to a [`WordList`] with [`WordList::screen`].
+///
+/// A list has one word per line, in any language. Blank lines and lines starting with `#` are
+/// skipped.
+///
+/// ```
+/// use hangman::words::{ScreenKind, WordList, WordScreen};
+///
+/// let mut words = WordList::parse("@language en\n@category animals\ncat\ndog\nhorse\n")
+///     .expect("the pack should be valid");
+/// let screen = WordScreen::parse("no dogs", ScreenKind::Block, "# Cats only\nDog\n")
+///     .expect("the blocklist should be valid");
+///
+/// assert_eq!(words.screen(&[screen]), [1]);
+/// assert_eq!(words.entries().len(), 2);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct WordScreen {
+    /// What the list is called in reports, such as the name of its file.
+    pub name: String,
+    /// Whether the words are removed or are the only ones kept.
+    pub kind: ScreenKind,
+    words: HashSet<String>,
+}
+
+/// A line of a blocklist or allowlist that couldn't be read.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WordScreenError {
+    /// The line the error is on, counting from 1.
+    pub line: usize,
+    /// What is wrong with the word on the line.
+    pub error: InvalidWordError,
+}
+
+impl fmt::Display for WordScreenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.error)
+    }
+}
+
+impl Error for WordScreenError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl WordScreen {
+    /// The blocklist of offensive words in English and French used by default.
+    pub fn family_friendly() -> Self {
+        WordScreen::parse(
+            "family-friendly",
+            ScreenKind::Block,
+            FAMILY_FRIENDLY_BLOCKLIST,
+        )
+        .expect("the built-in blocklist should be valid")
+    }
+
+    /// Reads a blocklist or allowlist, stopping at the first line that isn't a valid word.
+    pub fn parse(name: &str, kind: ScreenKind, contents: &str) -> Result<Self, WordScreenError> {
+        let mut words = HashSet::new();
+
+        for (index, line) in contents.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            words.insert(parse_word(line, false).map_err(|error| WordScreenError {
+                line: index + 1,
+                error,
+            })?);
+        }
+
+        Ok(WordScreen {
+            name: name.to_string(),
+            kind,
+            words,
+        })
+    }
+
+    /// Whether a word, ignoring case, gets through the list.
+    pub fn allows(&self, word: &str) -> bool {
+        let listed = self.words.contains(&word.to_uppercase());
+
+        match self.kind {
+            ScreenKind::Block => !listed,
+            ScreenKind::Allow => listed,
+        }
+    }
+
+    /// The number of words in the list.
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Whether there aren't any words in the list.
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+}
+
+impl WordList {
+    /// Removes the words that don't get through every list, returning how many words each list
+    /// removed. A word is only counted against the first list that removes it.
+    pub fn screen(&mut self, screens: &[WordScreen]) -> Vec<usize> {
+        let mut removed = vec![0; screens.len()];
+
+        self.entries.retain(|entry| {
+            match screens
+                .iter()
+                .position(|screen| !screen.allows(&entry.word))
+            {
+                Some(position) => {
+                    removed[position] += 1;
+                    false
+                }
+                None => true,
+            }
+        });
+        self.index = OnceLock::new();
+
+        removed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::words::BUILT_IN_PACKS;
+
+    #[test]
+    fn each_list_reports_the_words_it_removed_first() {
+        let mut words = WordList::parse(
+            "@language en\n@category animals\ncat\ndog\nhorse\nsheep\n\
+             @language fr\n@category animaux\nchat\n",
+        )
+        .unwrap();
+        let no_dogs = WordScreen::parse("no dogs", ScreenKind::Block, "dog\n").unwrap();
+        let farm = WordScreen::parse("farm", ScreenKind::Allow, "DOG\nHorse\nsheep\n").unwrap();
+
+        assert_eq!(words.screen(&[no_dogs, farm]), [1, 2]);
+        assert_eq!(
+            words
+                .entries()
+                .iter()
+                .map(|entry| entry.word.as_str())
+                .collect::<Vec<&str>>(),
+            ["HORSE", "SHEEP"]
+        );
+        assert_eq!(
+            WordScreen::parse("broken", ScreenKind::Block, "# Animals\ncat\nsea lion\n")
+                .unwrap_err()
+                .to_string(),
+            "line 3: the word contained \" \" but may only contain the letters A to Z"
+        );
+    }
+
+    #[test]
+    fn the_family_friendly_list_keeps_every_built_in_word() {
+        let family_friendly = [WordScreen::family_friendly()];
+
+        assert!(!family_friendly[0].is_empty());
+        assert!(!family_friendly[0].allows("Merde"));
+
+        for pack in BUILT_IN_PACKS {
+            let mut words = WordList::parse(pack).unwrap();
+
+            assert_eq!(words.screen(&family_friendly), [0]);
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use crate::words::{Difficulty, WordEntry};
+
+/// The most wrong guesses an easy word is expected to take.
+const EASY_MAXIMUM_MISSES: usize = 7;
+/// The most wrong guesses a medium word is expected to take.
+const MEDIUM_MAXIMUM_MISSES: usize = 11;
+
+/// How often each letter from A to Z appears in a list of words.
+///
+/// ```
+/// use hangman::words::{Difficulty, LetterFrequencies, WordList};
+///
+/// let words = WordList::parse("@language en\n@category animals\ncat\ncow\nowl\n")
+///     .expect("the pack should be valid");
+/// let frequencies = LetterFrequencies::of(words.entries());
+///
+/// assert_eq!(frequencies.count('o'), 2);
+/// assert_eq!(frequencies.most_common()[0], ('C', 2));
+/// assert_eq!(frequencies.estimated_misses("cow"), 0);
+/// assert_eq!(frequencies.estimated_difficulty("cow"), Difficulty::Easy);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LetterFrequencies {
+    counts: [usize; 26],
+}
+
+impl LetterFrequencies {
+    /// Counts every letter of every word.
+    pub fn of<'a>(entries: impl IntoIterator<Item = &'a WordEntry>) -> Self {
+        let mut frequencies = LetterFrequencies::default();
+
+        for entry in entries {
+            for char in entry.word.chars().filter(char::is_ascii_alphabetic) {
+                frequencies.counts[letter_position(char)] += 1;
+            }
+        }
+
+        frequencies
+    }
+
+    /// The number of times a letter appears, ignoring case.
+    pub fn count(&self, letter: char) -> usize {
+        if letter.is_ascii_alphabetic() {
+            self.counts[letter_position(letter)]
+        } else {
+            0
+        }
+    }
+
+    /// The number of letters counted.
+    pub fn total(&self) -> usize {
+        self.counts.iter().sum()
+    }
+
+    /// Every letter in uppercase with its count, the most common first and in alphabetical order
+    /// when counts are the same.
+    pub fn most_common(&self) -> Vec<(char, usize)> {
+        let mut letters = ('A'..='Z').zip(self.counts).collect::<Vec<(char, usize)>>();
+
+        letters.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+        letters
+    }
+
+    /// The number of wrong guesses made by guessing letters from the most to the least common
+    /// until every letter of a word has been found.
+    pub fn estimated_misses(&self, word: &str) -> usize {
+        let mut letters_left = word
+            .to_uppercase()
+            .chars()
+            .filter(char::is_ascii_alphabetic)
+            .collect::<Vec<char>>();
+        let mut misses = 0;
+
+        letters_left.sort_unstable();
+        letters_left.dedup();
+
+        for (letter, _) in self.most_common() {
+            if letters_left.is_empty() {
+                break;
+            }
+
+            match letters_left.iter().position(|left| *left == letter) {
+                Some(position) => {
+                    letters_left.remove(position);
+                }
+                None => misses += 1,
+            }
+        }
+
+        misses
+    }
+
+    /// How hard a word is likely to be, from the number of wrong guesses it's expected to take.
+    /// Short words with uncommon letters are the hardest.
+    pub fn estimated_difficulty(&self, word: &str) -> Difficulty {
+        match self.estimated_misses(word) {
+            misses if misses <= EASY_MAXIMUM_MISSES => Difficulty::Easy,
+            misses if misses <= MEDIUM_MAXIMUM_MISSES => Difficulty::Medium,
+            _ => Difficulty::Hard,
+        }
+    }
+}
+
+/// The position of a letter from A to Z in the alphabet, starting from 0.
+fn letter_position(letter: char) -> usize {
+    (letter.to_ascii_uppercase() as u8 - b'A') as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::words::WordList;
+
+    #[test]
+    fn words_with_uncommon_letters_are_estimated_to_be_harder() {
+        let words = WordList::parse(
+            "@language en\n@category animals\ncat\nrat\nbat\nhat\nmat\nseal\nstoat\nlynx\n",
+        )
+        .unwrap();
+        let frequencies = LetterFrequencies::of(words.entries());
+
+        assert_eq!(frequencies.total(), 28);
+        assert_eq!(
+            frequencies.most_common()[..3],
+            [('A', 7), ('T', 7), ('L', 2)]
+        );
+        assert_eq!(frequencies.count('?'), 0);
+        assert_eq!(frequencies.estimated_misses("cat"), 3);
+        assert_eq!(frequencies.estimated_difficulty("cat"), Difficulty::Easy);
+        assert_eq!(frequencies.estimated_misses("lynx"), 10);
+        assert_eq!(frequencies.estimated_difficulty("lynx"), Difficulty::Medium);
+        assert_eq!(frequencies.estimated_difficulty("quiz"), Difficulty::Hard);
+    }
+}
@@ -0,0 +1,144 @@
+use crate::words::{Difficulty, PickWordError, WordEntry, WordFilter, WordList};
+use rand::{
+    distributions::{Distribution, WeightedIndex},
+    Rng,
+};
+
+impl Difficulty {
+    /// How likely a word is to be picked at this difficulty, compared with the other words: its
+    /// frequency on easy, so common words come up more often, one over its frequency on hard, so
+    /// rare words do, and the same for every word on medium. Words without a frequency count as 1.
+    pub fn weight(self, entry: &WordEntry) -> f64 {
+        let frequency = entry.frequency.unwrap_or(1.0);
+
+        match self {
+            Difficulty::Easy => frequency,
+            Difficulty::Medium => 1.0,
+            Difficulty::Hard => 1.0 / frequency,
+        }
+    }
+}
+
+impl WordList {
+    /// Picks a word that matches a filter using `rng`, each word as likely as its
+    /// [`Difficulty::weight`].
+    ///
+    /// ```
+    /// use hangman::words::{Difficulty, WordFilter, WordList};
+    /// use rand::thread_rng;
+    ///
+    /// let words = WordList::parse("@language en\n@category animals\ncat 50\nokapi 0.1\n")
+    ///     .expect("the pack should be valid");
+    /// let okapi = &words.entries()[1];
+    ///
+    /// assert_eq!(Difficulty::Easy.weight(okapi), 0.1);
+    /// assert_eq!(Difficulty::Hard.weight(okapi), 10.0);
+    ///
+    /// let entry = words
+    ///     .random_weighted_entry(&WordFilter::new(), Difficulty::Hard, &mut thread_rng())
+    ///     .expect("a word should match");
+    ///
+    /// assert!(["CAT", "OKAPI"].contains(&entry.word.as_str()));
+    /// ```
+    pub fn random_weighted_entry<R: Rng + ?Sized>(
+        &self,
+        filter: &WordFilter,
+        difficulty: Difficulty,
+        rng: &mut R,
+    ) -> Result<&WordEntry, PickWordError> {
+        let matching = self.matching(filter).collect::<Vec<&WordEntry>>();
+
+        choose_weighted(&matching, difficulty, rng)?.ok_or_else(|| self.pick_error(filter))
+    }
+}
+
+/// Picks one of the words as likely as its weight at a difficulty, or `None` if there are none.
+/// Weights that can't be picked by, such as infinite ones, are an error rather than `None`.
+pub(crate) fn choose_weighted<'a, R: Rng + ?Sized>(
+    entries: &[&'a WordEntry],
+    difficulty: Difficulty,
+    rng: &mut R,
+) -> Result<Option<&'a WordEntry>, PickWordError> {
+    let weights = entries
+        .iter()
+        .map(|entry| difficulty.weight(entry))
+        .collect::<Vec<f64>>();
+
+    if weights.is_empty() {
+        return Ok(None);
+    }
+
+    // rand panics rather than returning an error when the total isn't finite.
+    if !weights.iter().sum::<f64>().is_finite() {
+        return Err(PickWordError::InvalidWeights(difficulty));
+    }
+
+    WeightedIndex::new(&weights)
+        .map(|index| Some(entries[index.sample(rng)]))
+        .map_err(|_| PickWordError::InvalidWeights(difficulty))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+    use std::collections::HashMap;
+
+    #[test]
+    fn common_words_are_favoured_on_easy_and_rare_words_on_hard() {
+        let words =
+            WordList::parse("@language en\n@category animals\ncat 1000\nhorse 10\nokapi 0.1\n")
+                .unwrap();
+        let mut rng = StdRng::seed_from_u64(50);
+        let mut picks = |difficulty| {
+            let mut counts = HashMap::<&str, usize>::new();
+
+            for _ in 0..10_000 {
+                let entry = words
+                    .random_weighted_entry(&WordFilter::new(), difficulty, &mut rng)
+                    .unwrap();
+
+                *counts.entry(entry.word.as_str()).or_default() += 1;
+            }
+
+            ["CAT", "HORSE", "OKAPI"].map(|word| counts.get(word).copied().unwrap_or_default())
+        };
+
+        let easy = picks(Difficulty::Easy);
+        let medium = picks(Difficulty::Medium);
+        let hard = picks(Difficulty::Hard);
+
+        // With weights of 1000, 10 and 0.1, about 99% of the picks are the favoured word.
+        assert!(easy[0] > 9_800, "{:?}", easy);
+        assert!(hard[2] > 9_800, "{:?}", hard);
+        assert!(
+            medium.iter().all(|count| (3_000..3_700).contains(count)),
+            "{:?}",
+            medium
+        );
+        assert_eq!(
+            WordList::default().random_weighted_entry(
+                &WordFilter::new(),
+                Difficulty::Easy,
+                &mut rng
+            ),
+            Err(PickWordError::NoWords)
+        );
+    }
+
+    #[test]
+    fn weights_that_cant_be_picked_by_are_an_error_instead_of_no_matching_words() {
+        let mut words = WordList::parse("@language en\n@category animals\nokapi\n").unwrap();
+        let mut rng = StdRng::seed_from_u64(50);
+
+        words.entries[0].frequency = Some(1e-310);
+
+        assert_eq!(
+            words
+                .random_weighted_entry(&WordFilter::new(), Difficulty::Hard, &mut rng)
+                .unwrap_err()
+                .to_string(),
+            "the word frequencies are too large or small to pick hard words by"
+        );
+    }
+}
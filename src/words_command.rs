@@ -0,0 +1,306 @@
+use crate::{
+    cli::WordsCommand,
+    settings::Settings,
+    word_packs::{self, LoadWordPacksError},
+};
+use hangman::words::{lint, LetterFrequencies, WordEntry, WordList};
+use std::{
+    collections::BTreeMap,
+    fmt, fs,
+    io::{self, Write},
+    path::PathBuf,
+};
+
+/// The width of the longest bar in a chart.
+const BAR_WIDTH: usize = 40;
+
+/// Runs a `words` command, returning whether the word packs are free of problems.
+pub(crate) fn run(
+    command: &WordsCommand,
+    settings: &Settings,
+    output: &mut impl Write,
+) -> Result<bool, RunError> {
+    match command {
+        WordsCommand::Lint(paths) => lint_packs(paths, output),
+        WordsCommand::Stats(paths) => print_stats(paths, settings, output).map(|_| true),
+    }
+}
+
+#[derive(Debug)]
+pub(crate) enum RunError {
+    Load(LoadWordPacksError),
+    Output(io::Error),
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RunError::Load(error) => write!(f, "{}", error),
+            RunError::Output(error) => write!(f, "could not write the report: {}", error),
+        }
+    }
+}
+
+impl From<LoadWordPacksError> for RunError {
+    fn from(error: LoadWordPacksError) -> Self {
+        RunError::Load(error)
+    }
+}
+
+impl From<io::Error> for RunError {
+    fn from(error: io::Error) -> Self {
+        RunError::Output(error)
+    }
+}
+
+/// Lints the given packs, or the packs next to the config file if none are given.
+fn lint_packs(paths: &[PathBuf], output: &mut impl Write) -> Result<bool, RunError> {
+    let paths = match paths {
+        [] => word_packs::pack_files()?,
+        paths => paths.to_vec(),
+    };
+
+    if paths.is_empty() {
+        writeln!(output, "There are no word packs to check.")?;
+        return Ok(true);
+    }
+
+    let mut no_problems = true;
+
+    for path in paths {
+        let contents = fs::read_to_string(&path)
+            .map_err(|error| LoadWordPacksError::Io(path.clone(), error))?;
+        let report = lint(&contents);
+
+        match report.issues.len() {
+            0 => writeln!(output, "{}: no problems", path.display())?,
+            1 => writeln!(output, "{}: 1 problem", path.display())?,
+            count => writeln!(output, "{}: {} problems", path.display(), count)?,
+        }
+
+        for issue in &report.issues {
+            writeln!(output, "  {}", issue)?;
+        }
+
+        writeln!(output, "  Word lengths:")?;
+        print_chart(
+            output,
+            report
+                .lengths
+                .iter()
+                .map(|(length, count)| (format!("{:>2} letters", length), *count)),
+        )?;
+        writeln!(output)?;
+
+        no_problems &= report.issues.is_empty();
+    }
+
+    Ok(no_problems)
+}
+
+/// Prints how many words each blocklist and allowlist removed, then the letter frequencies and
+/// estimated difficulties of the words in each language of the given packs, or of the words the
+/// game would load if none are given.
+fn print_stats(
+    paths: &[PathBuf],
+    settings: &Settings,
+    output: &mut impl Write,
+) -> Result<(), RunError> {
+    let (words, removed) = match paths {
+        [] => word_packs::load_words(settings.family_friendly)?,
+        paths => {
+            let mut words = WordList::default();
+
+            for path in paths {
+                words.extend(word_packs::load_pack(path)?);
+            }
+
+            let removed = word_packs::screen(&mut words, settings.family_friendly)?;
+
+            (words, removed)
+        }
+    };
+
+    if !removed.is_empty() {
+        writeln!(output, "Words removed by each list:")?;
+
+        for (name, count) in removed {
+            writeln!(output, "    {}  {}", name, count)?;
+        }
+
+        writeln!(output)?;
+    }
+
+    let mut languages = BTreeMap::<&str, Vec<&WordEntry>>::new();
+
+    for entry in words.entries() {
+        languages.entry(&entry.language).or_default().push(entry);
+    }
+
+    for (language, entries) in languages {
+        let frequencies = LetterFrequencies::of(entries.iter().copied());
+
+        writeln!(
+            output,
+            "Letter frequencies in \"{}\" ({} words, {} letters):",
+            language,
+            entries.len(),
+            frequencies.total()
+        )?;
+        print_chart(
+            output,
+            frequencies
+                .most_common()
+                .into_iter()
+                .filter(|(_, count)| *count > 0)
+                .map(|(letter, count)| {
+                    (
+                        format!(
+                            "{} {:>5.1}%",
+                            letter,
+                            count as f64 * 100.0 / frequencies.total() as f64
+                        ),
+                        count,
+                    )
+                }),
+        )?;
+        writeln!(output)?;
+
+        let word_width = entries
+            .iter()
+            .map(|entry| entry.word.chars().count())
+            .chain([4])
+            .max()
+            .unwrap_or_default();
+        let category_width = entries
+            .iter()
+            .map(|entry| entry.category.chars().count())
+            .chain([8])
+            .max()
+            .unwrap_or_default();
+
+        writeln!(output, "Estimated difficulty in \"{}\":", language)?;
+        writeln!(
+            output,
+            "  {:word_width$}  {:category_width$}  {:6}  {:9}  MISSES",
+            "WORD", "CATEGORY", "PACK", "ESTIMATED"
+        )?;
+
+        for entry in entries {
+            writeln!(
+                output,
+                "  {:word_width$}  {:category_width$}  {:6}  {:9}  {}",
+                entry.word,
+                entry.category,
+                entry.difficulty.name(),
+                frequencies.estimated_difficulty(&entry.word).name(),
+                frequencies.estimated_misses(&entry.word)
+            )?;
+        }
+
+        writeln!(output)?;
+    }
+
+    Ok(())
+}
+
+/// Prints a row for each label with a bar as long as its count, the longest filling the width.
+fn print_chart(
+    output: &mut impl Write,
+    rows: impl Iterator<Item = (String, usize)>,
+) -> io::Result<()> {
+    let rows = rows.collect::<Vec<(String, usize)>>();
+    let largest = rows
+        .iter()
+        .map(|(_, count)| *count)
+        .max()
+        .unwrap_or_default();
+
+    for (label, count) in rows {
+        writeln!(
+            output,
+            "    {}  {:>6}  {}",
+            label,
+            count,
+            "#".repeat((count * BAR_WIDTH).div_ceil(largest))
+        )?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, process};
+
+    fn run_on(
+        name: &str,
+        command: fn(Vec<PathBuf>) -> WordsCommand,
+        contents: &str,
+    ) -> (bool, String) {
+        let directory = env::temp_dir().join(format!("hangman-{}-{}", name, process::id()));
+        let path = directory.join("animals.pack");
+        let mut output = Vec::new();
+
+        fs::create_dir_all(&directory).unwrap();
+        fs::write(&path, contents).unwrap();
+
+        let no_problems = run(
+            &command(vec![path.clone()]),
+            &Settings::default(),
+            &mut output,
+        )
+        .unwrap();
+
+        fs::remove_dir_all(&directory).unwrap();
+
+        let output = String::from_utf8(output)
+            .unwrap()
+            .replace(&path.display().to_string(), "animals.pack");
+
+        (no_problems, output)
+    }
+
+    #[test]
+    fn lint_reports_every_problem_and_the_word_lengths() {
+        let (no_problems, output) = run_on(
+            "lint",
+            WordsCommand::Lint,
+            "@language en\n@category animals\ncat \ndog\nsea lion\nhorse\n",
+        );
+
+        assert!(!no_problems);
+        assert_eq!(
+            output,
+            "animals.pack: 2 problems\n\
+             \x20 line 3: the line starts or ends with spaces\n\
+             \x20 line 5: the word contained \" \" but may only contain the letters A to Z\n\
+             \x20 Word lengths:\n\
+             \x20    3 letters       2  ########################################\n\
+             \x20    5 letters       1  ####################\n\n"
+        );
+    }
+
+    #[test]
+    fn stats_report_letter_frequencies_and_estimated_difficulty() {
+        let (no_problems, output) = run_on(
+            "stats",
+            WordsCommand::Stats,
+            "@language en\n@category animals\n@difficulty hard\ncat\ncow\nshit\n",
+        );
+
+        assert!(no_problems);
+        assert!(output.starts_with(
+            "Words removed by each list:\n\
+             \x20   family-friendly  1\n\n\
+             Letter frequencies in \"en\" (2 words, 6 letters):\n\
+             \x20   C  33.3%       2  ########################################\n\
+             \x20   A  16.7%       1  ####################\n"
+        ));
+        assert!(output.contains(
+            "  WORD  CATEGORY  PACK    ESTIMATED  MISSES\n\
+             \x20 CAT   animals   hard    easy       1\n"
+        ));
+    }
+}
@@ -0,0 +1,75 @@
+use hangman::{
+    words::PickWordError, CompleteGameStatus, Game, GameStatus, GuessStatus, HintError,
+    InvalidWordError, MakeGuessError, MakeGuessSuccess,
+};
+use std::error::Error;
+
+#[test]
+fn a_game_can_be_won_through_the_public_api() {
+    let mut game = Game::from_word("rust", 2).unwrap();
+
+    assert_eq!(
+        game.make_guess("r"),
+        Ok(MakeGuessSuccess::Correct(String::from("R")))
+    );
+    assert_eq!(
+        game.make_guess("x"),
+        Ok(MakeGuessSuccess::Incorrect(String::from("X")))
+    );
+    assert_eq!(game.blanked_out_letters().concat(), "R___");
+    assert_eq!(game.solve("rust"), Ok(true));
+
+    assert_eq!(game.status(), GameStatus::Complete(CompleteGameStatus::Won));
+    assert_eq!(game.lives_remaining(), 1);
+    assert_eq!(
+        game.guesses()[..2],
+        [
+            (String::from("R"), GuessStatus::Correct),
+            (String::from("X"), GuessStatus::Incorrect)
+        ]
+    );
+}
+
+#[test]
+fn a_game_can_be_lost_through_the_public_api() {
+    let mut game = Game::from_word("rust", 1).unwrap();
+
+    assert_eq!(game.use_hint(), Err(HintError::NotEnoughLives));
+    assert_eq!(
+        game.make_guess("a"),
+        Ok(MakeGuessSuccess::Incorrect(String::from("A")))
+    );
+
+    assert_eq!(
+        game.status(),
+        GameStatus::Complete(CompleteGameStatus::Lost)
+    );
+    assert_eq!(game.make_guess("r"), Err(MakeGuessError::GameComplete));
+}
+
+#[test]
+fn errors_implement_the_standard_error_trait() {
+    let errors: [Box<dyn Error>; 5] = [
+        Box::new(MakeGuessError::AlreadyGuessed(String::from("R"))),
+        Box::new(HintError::GameComplete),
+        Box::new(InvalidWordError::InvalidCharacter('1')),
+        Box::new(PickWordError::NoWords),
+        Box::new(Game::new(6, 40).unwrap_err()),
+    ];
+
+    let messages = errors
+        .iter()
+        .map(|error| error.to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        messages,
+        [
+            "\"R\" has already been guessed",
+            "the game is already over",
+            "the word contained \"1\" but may only contain the letters A to Z",
+            "there are no words to pick from",
+            "there are no words with at least 40 letters",
+        ]
+    );
+}